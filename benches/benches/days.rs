@@ -0,0 +1,98 @@
+use advent_of_code_2020_challenges::*;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use std::path::PathBuf;
+
+fn read_input_for_day(day: u32) -> Option<String> {
+    let mut file_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    file_path.push("..");
+    file_path.push("input");
+    file_path.push(format!("day{:02}.txt", day));
+
+    let data = std::fs::read_to_string(file_path).ok()?;
+    normalize_day_input(day, &data, false).ok()
+}
+
+// benchmarks parsing, silver and gold separately as one group per day, skipping
+// days without an input file and both parts when the input fails to parse
+fn bench_challenges<D, F>(criterion: &mut Criterion, day: u32, parse_challenge: F)
+where
+    D: SilverChallenge + GoldChallenge + Clone,
+    <D as SilverChallenge>::Answer: std::fmt::Debug,
+    <D as SilverChallenge>::Error: std::fmt::Debug,
+    <D as GoldChallenge>::Answer: std::fmt::Debug,
+    <D as GoldChallenge>::Error: std::fmt::Debug,
+    F: Fn(&str) -> Result<D, String>,
+{
+    let data = match read_input_for_day(day) {
+        Some(data) => data,
+        None => return,
+    };
+
+    let mut group = criterion.benchmark_group(format!("day{:02}", day));
+    group.bench_function("parse", |bencher| {
+        bencher.iter(|| parse_challenge(black_box(&data)))
+    });
+
+    if let Ok(challenge) = parse_challenge(&data) {
+        // parts take the challenge mutably, so each iteration solves a fresh copy
+        group.bench_function("silver", |bencher| {
+            bencher.iter_batched(
+                || challenge.clone(),
+                |mut challenge| challenge.attempt_silver(),
+                BatchSize::SmallInput,
+            )
+        });
+        group.bench_function("gold", |bencher| {
+            bencher.iter_batched(
+                || challenge.clone(),
+                |mut challenge| challenge.attempt_gold(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+macro_rules! bench_days {
+    ($($feature:literal: $day:literal => $challenge:ident),* $(,)?) => {
+        // only the days compiled in through their `dayNN` feature are benchmarked
+        fn bench_all_days(criterion: &mut Criterion) {
+            $(
+                #[cfg(feature = $feature)]
+                bench_challenges(criterion, $day, $challenge::new);
+            )*
+        }
+    };
+}
+
+bench_days! {
+    "day01": 1 => Day01,
+    "day02": 2 => Day02,
+    "day03": 3 => Day03,
+    "day04": 4 => Day04,
+    "day05": 5 => Day05,
+    "day06": 6 => Day06,
+    "day07": 7 => Day07,
+    "day08": 8 => Day08,
+    "day09": 9 => Day09,
+    "day10": 10 => Day10,
+    "day11": 11 => Day11,
+    "day12": 12 => Day12,
+    "day13": 13 => Day13,
+    "day14": 14 => Day14,
+    "day15": 15 => Day15,
+    "day16": 16 => Day16,
+    "day17": 17 => Day17,
+    "day18": 18 => Day18,
+    "day19": 19 => Day19,
+    "day20": 20 => Day20,
+    "day21": 21 => Day21,
+    "day22": 22 => Day22,
+    "day23": 23 => Day23,
+    "day24": 24 => Day24,
+    "day25": 25 => Day25,
+}
+
+criterion_group!(benches, bench_all_days);
+criterion_main!(benches);
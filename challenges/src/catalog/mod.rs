@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests;
+
+// every day's README is embedded regardless of the days compiled in, so that
+// puzzles can be listed by title even in focused builds
+const READMES: [&str; 25] = [
+    include_str!("../day01/README.md"),
+    include_str!("../day02/README.md"),
+    include_str!("../day03/README.md"),
+    include_str!("../day04/README.md"),
+    include_str!("../day05/README.md"),
+    include_str!("../day06/README.md"),
+    include_str!("../day07/README.md"),
+    include_str!("../day08/README.md"),
+    include_str!("../day09/README.md"),
+    include_str!("../day10/README.md"),
+    include_str!("../day11/README.md"),
+    include_str!("../day12/README.md"),
+    include_str!("../day13/README.md"),
+    include_str!("../day14/README.md"),
+    include_str!("../day15/README.md"),
+    include_str!("../day16/README.md"),
+    include_str!("../day17/README.md"),
+    include_str!("../day18/README.md"),
+    include_str!("../day19/README.md"),
+    include_str!("../day20/README.md"),
+    include_str!("../day21/README.md"),
+    include_str!("../day22/README.md"),
+    include_str!("../day23/README.md"),
+    include_str!("../day24/README.md"),
+    include_str!("../day25/README.md"),
+];
+
+// the title following the day in a heading such as `# Day 1: Report Repair`
+pub fn title_from_readme(readme: &str) -> Option<&str> {
+    let heading = readme
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("# "))?;
+    let (_, title) = heading.split_once(':')?;
+
+    match title.trim() {
+        "" => None,
+        title => Some(title),
+    }
+}
+
+pub fn puzzle_title(day: u32) -> Option<&'static str> {
+    match day {
+        1..=25 => title_from_readme(READMES[day as usize - 1]),
+        _ => None,
+    }
+}
@@ -0,0 +1,24 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+#[test]
+fn title_follows_day_in_first_heading() {
+    let readme = "# Day 1: Report Repair\n\nOriginal source\n\n## Silver Challenge";
+
+    assert_eq!(title_from_readme(readme), Some("Report Repair"));
+}
+
+#[test]
+fn heading_without_title_has_none() {
+    assert_eq!(title_from_readme("# Day 20\n\n## Silver Challenge"), None);
+    assert_eq!(title_from_readme("# Day 20:\n"), None);
+    assert_eq!(title_from_readme("no heading"), None);
+}
+
+#[test]
+fn titles_come_from_embedded_readmes() {
+    assert_eq!(puzzle_title(1), Some("Report Repair"));
+    assert_eq!(puzzle_title(9), Some("Encoding Error"));
+    assert_eq!(puzzle_title(0), None);
+    assert_eq!(puzzle_title(26), None);
+}
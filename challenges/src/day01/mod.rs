@@ -1,39 +1,335 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::generator::SeededRng;
+use crate::{ChallengeInput, GoldChallenge, InputGenerator, Inspect, SilverChallenge};
+use std::collections::HashSet;
+
+pub const DEFAULT_TARGET: u64 = 2020;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day01 {
-    data: String,
+    entries: Vec<u64>,
+    target: u64,
 }
 
 impl Day01 {
     pub fn new(data: &str) -> Result<Self, String> {
-        Ok(Self { data: data.into() })
+        let entries = data
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                line.trim().parse().map_err(|_| {
+                    format!(
+                        "Invalid expense report entry on line {}: {:?}",
+                        index + 1,
+                        line
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if entries.is_empty() {
+            return Err("Expense report has no entries.".into());
+        }
+
+        Ok(Self {
+            entries,
+            target: DEFAULT_TARGET,
+        })
+    }
+
+    // searches for entries summing to another target than 2020
+    pub fn with_target(mut self, target: u64) -> Self {
+        self.target = target;
+        self
+    }
+
+    pub fn entries(&self) -> &[u64] {
+        &self.entries
+    }
+
+    pub fn target(&self) -> u64 {
+        self.target
+    }
+
+    pub fn find_combinations(&self, k: usize) -> Vec<Vec<u64>> {
+        find_k_sum(&self.entries, k, self.target)
+    }
+
+    // The single combination of `k` entries summing to the target, with the line of
+    // each entry. Equal values on separate lines are separate entries, so a value
+    // can be used as many times as it appears in the report but no more.
+    pub fn matching_entries(&self, k: usize) -> Result<Vec<ExpenseEntry>, String> {
+        let combinations = find_k_sum_indices(&self.entries, k, self.target);
+        match combinations.as_slice() {
+            [] => Err(format!("No {} entries sum to {}.", k, self.target)),
+            [combination] => Ok(combination
+                .iter()
+                .map(|index| ExpenseEntry {
+                    line: index + 1,
+                    value: self.entries[*index],
+                })
+                .collect()),
+            _ => Err(format!(
+                "Found {} combinations of {} entries summing to {}, expected one.",
+                combinations.len(),
+                k,
+                self.target
+            )),
+        }
+    }
+
+    // the puzzle answer is the product of the matching entries
+    fn product_of_matching_entries(&self, k: usize) -> Result<u64, String> {
+        let entries = self.matching_entries(k)?;
+        entries
+            .iter()
+            .try_fold(1u64, |product, entry| product.checked_mul(entry.value))
+            .ok_or_else(|| format!("Product of {:?} overflows.", entries))
+    }
+}
+
+impl ChallengeInput for Day01 {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExpenseEntry {
+    pub line: usize,
+    pub value: u64,
+}
+
+// All distinct combinations of `k` entries summing to `target`, each in ascending
+// order and listed in ascending order. Each entry is used at most once, and
+// combinations of equal values are only listed once.
+pub fn find_k_sum(entries: &[u64], k: usize, target: u64) -> Vec<Vec<u64>> {
+    find_k_sum_indices(entries, k, target)
+        .iter()
+        .map(|combination| combination.iter().map(|index| entries[*index]).collect())
+        .collect()
+}
+
+// Same as `find_k_sum`, giving the index of each entry instead of its value. Where
+// equal values could be used interchangeably, the earliest entries are given.
+pub fn find_k_sum_indices(entries: &[u64], k: usize, target: u64) -> Vec<Vec<usize>> {
+    // entries above the target can never be part of a combination summing to it,
+    // and sorting by value then index keeps the earliest of equal values first
+    let mut sorted = entries
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, entry)| *entry <= target)
+        .map(|(index, entry)| (entry, index))
+        .collect::<Vec<_>>();
+    sorted.sort_unstable();
+
+    let mut combinations = Vec::new();
+    find_sorted_k_sum(&sorted, k, target, &mut Vec::new(), &mut combinations);
+    combinations
+}
+
+// fixes the smallest entry of a combination and recurses on the entries after it,
+// until two entries remain to be found with two pointers, for O(n^(k-1)) overall
+fn find_sorted_k_sum(
+    sorted: &[(u64, usize)],
+    k: usize,
+    target: u64,
+    prefix: &mut Vec<usize>,
+    combinations: &mut Vec<Vec<usize>>,
+) {
+    match k {
+        0 => {
+            if target == 0 {
+                combinations.push(prefix.clone());
+            }
+        }
+        1 => {
+            let first = sorted.partition_point(|(entry, _)| *entry < target);
+            if let Some((_, index)) = sorted.get(first).filter(|(entry, _)| *entry == target) {
+                let mut combination = prefix.clone();
+                combination.push(*index);
+                combinations.push(combination);
+            }
+        }
+        2 => find_sorted_two_sum(sorted, target, prefix, combinations),
+        _ => {
+            for (position, (entry, index)) in sorted.iter().enumerate() {
+                // every remaining entry is at least this one, so none can reach the target
+                if u128::from(*entry) * k as u128 > u128::from(target) {
+                    break;
+                }
+                if position > 0 && sorted[position - 1].0 == *entry {
+                    continue;
+                }
+
+                prefix.push(*index);
+                find_sorted_k_sum(
+                    &sorted[position + 1..],
+                    k - 1,
+                    target - entry,
+                    prefix,
+                    combinations,
+                );
+                prefix.pop();
+            }
+        }
+    }
+}
+
+fn find_sorted_two_sum(
+    sorted: &[(u64, usize)],
+    target: u64,
+    prefix: &[usize],
+    combinations: &mut Vec<Vec<usize>>,
+) {
+    // entries above the target can never be part of a sum, which also keeps
+    // `target - high_entry` below from underflowing
+    let sorted = &sorted[..sorted.partition_point(|(entry, _)| *entry <= target)];
+    if sorted.len() < 2 {
+        return;
+    }
+
+    let (mut low, mut high) = (0, sorted.len() - 1);
+    while low < high {
+        let (low_entry, high_entry) = (sorted[low].0, sorted[high].0);
+        match low_entry.cmp(&(target - high_entry)) {
+            std::cmp::Ordering::Less => low += 1,
+            std::cmp::Ordering::Greater => high -= 1,
+            std::cmp::Ordering::Equal => {
+                // the earliest entry of the high value that is not the low entry itself
+                let first_high = low
+                    + 1
+                    + sorted[low + 1..=high].partition_point(|(entry, _)| *entry < high_entry);
+                let mut combination = prefix.to_vec();
+                combination.extend([sorted[low].1, sorted[first_high].1]);
+                combinations.push(combination);
+
+                while low < high && sorted[low].0 == low_entry {
+                    low += 1;
+                }
+                while low < high && sorted[high].0 == high_entry {
+                    high -= 1;
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Day01 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines = self.entries.iter().map(|entry| entry.to_string());
+        write!(f, "{}", lines.collect::<Vec<_>>().join("\n"))
     }
 }
 
 impl SilverChallenge for Day01 {
-    type Answer = ();
-    type Error = &'static str;
+    type Answer = u64;
+    type Error = String;
 
     fn attempt_silver(&mut self) -> Result<Self::Answer, Self::Error>
     where
         Self::Answer: std::fmt::Debug,
     {
-        Err("NYI")
+        self.product_of_matching_entries(2)
     }
 }
 
 impl GoldChallenge for Day01 {
-    type Answer = ();
-    type Error = &'static str;
+    type Answer = u64;
+    type Error = String;
 
     fn attempt_gold(&mut self) -> Result<Self::Answer, Self::Error>
     where
         Self::Answer: std::fmt::Debug,
     {
-        Err("NYI")
+        self.product_of_matching_entries(3)
+    }
+}
+
+impl Inspect for Day01 {
+    fn inspect(&self) -> Vec<(&'static str, String)> {
+        let distinct = self.entries.iter().collect::<HashSet<_>>().len();
+        let above_target = self
+            .entries
+            .iter()
+            .filter(|entry| **entry > self.target)
+            .count();
+
+        vec![
+            ("Entries", self.entries.len().to_string()),
+            ("Distinct entries", distinct.to_string()),
+            ("Target", self.target.to_string()),
+            ("Entries above target", above_target.to_string()),
+        ]
     }
 }
+
+impl InputGenerator for Day01 {
+    fn generate_input(size: usize, seed: u64) -> String {
+        let mut rng = SeededRng::new(seed);
+
+        // one pair and one triple summing to 2020 are planted, re-rolled until
+        // they do not accidentally form any other pair or triple between them
+        let planted = loop {
+            let pair_low = rng.range(1..=1009);
+            let triple_first = rng.range(1..=672);
+            let triple_second = rng.range(1..=672);
+            let candidate = [
+                pair_low,
+                2020 - pair_low,
+                triple_first,
+                triple_second,
+                2020 - triple_first - triple_second,
+            ];
+            if has_only_planted_sums(&candidate) {
+                break candidate;
+            }
+        };
+
+        // filler entries are all above 1010 so no two of them can be part of the
+        // same sum, and values completing a sum with planted entries are avoided
+        let mut forbidden = HashSet::new();
+        for (index, first) in planted.iter().enumerate() {
+            forbidden.insert(2020 - first);
+            for second in &planted[index + 1..] {
+                if first + second < 2020 {
+                    forbidden.insert(2020 - first - second);
+                }
+            }
+        }
+
+        let mut entries = Vec::with_capacity(size.max(planted.len()));
+        while entries.len() + planted.len() < size {
+            let entry = rng.range(1011..=2019);
+            if !forbidden.contains(&entry) {
+                entries.push(entry);
+            }
+        }
+        for entry in planted.iter() {
+            let position = rng.index(entries.len() + 1);
+            entries.insert(position, *entry);
+        }
+
+        entries
+            .iter()
+            .map(|entry| entry.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn has_only_planted_sums(planted: &[u64; 5]) -> bool {
+    let distinct = planted.iter().collect::<HashSet<_>>().len() == planted.len();
+
+    let pairs = (0..5)
+        .flat_map(|first| (first + 1..5).map(move |second| (first, second)))
+        .filter(|&(first, second)| planted[first] + planted[second] == 2020)
+        .count();
+
+    let triples = (0..5)
+        .flat_map(|first| (first + 1..5).map(move |second| (first, second)))
+        .flat_map(|(first, second)| (second + 1..5).map(move |third| (first, second, third)))
+        .filter(|&(first, second, third)| planted[first] + planted[second] + planted[third] == 2020)
+        .count();
+
+    distinct && pairs == 1 && triples == 1
+}
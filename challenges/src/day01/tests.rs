@@ -1,44 +1,292 @@
 // import everything from the parent module file (in this case mod.rs)
 use super::*;
 
-const SAMPLE_DATA: &'static str = "<replace this with sample data from challenge>";
+const SAMPLE_DATA: &str = "1721
+979
+366
+299
+675
+1456";
+
+sample_tests!(Day01, [
+    { data: SAMPLE_DATA, silver: Ok(514579), gold: Ok(241861950) },
+], input: { file: "day01.txt", silver: Ok(898299), gold: Ok(143933922) });
+
+round_trip_property!(Day01, crate::properties::expense_report());
+
+// checks every combination of indices, as the reference for the optimized search
+fn brute_force_k_sum(entries: &[u64], k: usize, target: u64) -> Vec<Vec<u64>> {
+    fn search(
+        entries: &[u64],
+        k: usize,
+        target: u64,
+        prefix: &mut Vec<u64>,
+        combinations: &mut Vec<Vec<u64>>,
+    ) {
+        if k == 0 {
+            if prefix.iter().sum::<u64>() == target {
+                let mut combination = prefix.clone();
+                combination.sort_unstable();
+                combinations.push(combination);
+            }
+            return;
+        }
+        for (index, entry) in entries.iter().enumerate() {
+            prefix.push(*entry);
+            search(&entries[index + 1..], k - 1, target, prefix, combinations);
+            prefix.pop();
+        }
+    }
+
+    let mut combinations = Vec::new();
+    search(entries, k, target, &mut Vec::new(), &mut combinations);
+    combinations.sort_unstable();
+    combinations.dedup();
+    combinations
+}
+
+impl Day01 {
+    fn brute_force_triples(&self) -> Vec<Vec<u64>> {
+        brute_force_k_sum(self.entries(), 3, self.target())
+    }
+
+    fn optimized_triples(&self) -> Vec<Vec<u64>> {
+        self.find_combinations(3)
+    }
+}
+
+equivalent_variants_property!(
+    Day01,
+    crate::properties::expense_report(),
+    brute_force_triples,
+    optimized_triples
+);
+
+#[test]
+fn k_sum_finds_all_distinct_combinations() {
+    let entries = [1, 2, 3, 4, 5, 6];
+
+    assert_eq!(
+        find_k_sum(&entries, 2, 7),
+        vec![vec![1, 6], vec![2, 5], vec![3, 4]]
+    );
+    assert_eq!(
+        find_k_sum(&entries, 3, 10),
+        vec![vec![1, 3, 6], vec![1, 4, 5], vec![2, 3, 5]]
+    );
+}
+
+#[test]
+fn k_sum_lists_equal_values_once() {
+    let entries = [5, 5, 5, 5, 10, 0];
+
+    assert_eq!(find_k_sum(&entries, 2, 10), vec![vec![0, 10], vec![5, 5]]);
+    assert_eq!(
+        find_k_sum(&entries, 3, 15),
+        vec![vec![0, 5, 10], vec![5, 5, 5]]
+    );
+}
+
+#[test]
+fn k_sum_handles_small_k() {
+    let entries = [3, 7, 11];
+
+    assert_eq!(find_k_sum(&entries, 0, 0), vec![Vec::<u64>::new()]);
+    assert_eq!(find_k_sum(&entries, 0, 7), Vec::<Vec<u64>>::new());
+    assert_eq!(find_k_sum(&entries, 1, 7), vec![vec![7]]);
+    assert_eq!(find_k_sum(&entries, 4, 21), Vec::<Vec<u64>>::new());
+}
 
 #[test]
-fn sample_data_builds_ok() {
+fn k_sum_matches_brute_force_for_larger_k() {
+    let data = Day01::generate_input(40, 11);
+    let challenge = Day01::new(&data).unwrap();
+
+    for k in 1..=4 {
+        assert_eq!(
+            challenge.find_combinations(k),
+            brute_force_k_sum(challenge.entries(), k, 2020),
+            "combinations of {} entries",
+            k
+        );
+    }
+}
+
+#[test]
+fn k_sum_does_not_overflow_on_large_entries() {
+    let entries = [u64::MAX, u64::MAX - 1, 1, u64::MAX - 2];
+
+    assert_eq!(
+        find_k_sum(&entries, 2, u64::MAX),
+        vec![vec![1, u64::MAX - 1]]
+    );
+    assert_eq!(find_k_sum(&entries, 3, u64::MAX), Vec::<Vec<u64>>::new());
+}
+
+#[test]
+fn other_targets_are_supported() {
+    let mut challenge = Day01::new(SAMPLE_DATA).unwrap().with_target(1345);
+
+    assert_eq!(challenge.target(), 1345);
+    assert_eq!(challenge.attempt_silver(), Ok(979 * 366));
+}
+
+#[test]
+fn missing_combination_is_an_error() {
+    let mut challenge = Day01::new("1\n2\n3").unwrap();
+
+    assert_eq!(
+        challenge.attempt_silver(),
+        Err("No 2 entries sum to 2020.".into())
+    );
+    assert_eq!(
+        challenge.attempt_gold(),
+        Err("No 3 entries sum to 2020.".into())
+    );
+}
+
+#[test]
+fn several_combinations_are_an_error() {
+    let mut challenge = Day01::new("1\n2\n3\n4").unwrap().with_target(5);
+
+    assert_eq!(
+        challenge.attempt_silver(),
+        Err("Found 2 combinations of 2 entries summing to 5, expected one.".into())
+    );
+}
+
+#[test]
+fn invalid_entry_is_reported_with_line() {
+    assert_eq!(
+        Day01::new("1721\nabc"),
+        Err("Invalid expense report entry on line 2: \"abc\"".into())
+    );
+}
+
+#[test]
+fn generated_data_builds_ok() {
+    let data = Day01::generate_input(1_000, 2020);
+
     // unwrap will fail the test here if the day input parsing returned an Err
-    Day01::new(SAMPLE_DATA).unwrap();
+    Day01::new(&data).unwrap();
 }
 
 #[test]
-fn sample_data_for_silver_solution_has_ok_result() {
-    let mut challenge = Day01::new(SAMPLE_DATA).unwrap();
+fn generated_data_is_reproducible_from_seed() {
+    assert_eq!(Day01::generate_input(100, 7), Day01::generate_input(100, 7));
+    assert_ne!(Day01::generate_input(100, 7), Day01::generate_input(100, 8));
+}
+
+#[test]
+fn generated_data_has_single_pair_and_triple() {
+    let data = Day01::generate_input(200, 2020);
+    let entries = data
+        .lines()
+        .map(|line| line.parse::<u64>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(entries.len(), 200);
+
+    let mut pairs = 0;
+    let mut triples = 0;
+    for first in 0..entries.len() {
+        for second in first + 1..entries.len() {
+            if entries[first] + entries[second] == 2020 {
+                pairs += 1;
+            }
+            for third in second + 1..entries.len() {
+                if entries[first] + entries[second] + entries[third] == 2020 {
+                    triples += 1;
+                }
+            }
+        }
+    }
+    assert_eq!((pairs, triples), (1, 1));
+}
+
+#[test]
+fn repeated_value_can_pair_with_itself() {
+    let challenge = Day01::new("1010\n3\n1010\n7").unwrap();
 
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_silver().unwrap();
+    assert_eq!(
+        challenge.matching_entries(2),
+        Ok(vec![
+            ExpenseEntry {
+                line: 1,
+                value: 1010
+            },
+            ExpenseEntry {
+                line: 3,
+                value: 1010
+            },
+        ])
+    );
 }
 
 #[test]
-fn sample_data_for_silver_solution_has_ok_result_of() {
-    let mut challenge = Day01::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_silver();
+fn single_value_cannot_pair_with_itself() {
+    let mut challenge = Day01::new("1010\n3\n7").unwrap();
 
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
+    assert_eq!(
+        challenge.attempt_silver(),
+        Err("No 2 entries sum to 2020.".into())
+    );
 }
 
 #[test]
-fn sample_data_for_gold_solution_has_ok_result() {
-    let mut challenge = Day01::new(SAMPLE_DATA).unwrap();
+fn value_is_used_no_more_often_than_it_appears() {
+    let challenge = Day01::new("5\n5\n10").unwrap().with_target(15);
 
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_gold().unwrap();
+    assert_eq!(
+        find_k_sum(challenge.entries(), 3, 15),
+        Vec::<Vec<u64>>::new()
+    );
+    assert_eq!(
+        find_k_sum_indices(&[5, 5, 5, 10], 3, 15),
+        vec![vec![0, 1, 2]]
+    );
+}
+
+#[test]
+fn matching_entries_have_line_numbers() {
+    let challenge = Day01::new(SAMPLE_DATA).unwrap();
+    let lines = |k| {
+        challenge
+            .matching_entries(k)
+            .unwrap()
+            .iter()
+            .map(|entry| entry.line)
+            .collect::<Vec<_>>()
+    };
+
+    // ordered by value, 299 on line 4 before 1721 on line 1
+    assert_eq!(lines(2), vec![4, 1]);
+    assert_eq!(lines(3), vec![3, 5, 2]);
+}
+
+#[test]
+fn equal_values_use_earliest_lines() {
+    assert_eq!(
+        find_k_sum_indices(&[7, 3, 7, 3, 7], 2, 10),
+        vec![vec![1, 0]]
+    );
+    assert_eq!(
+        find_k_sum_indices(&[4, 4, 4, 4], 3, 12),
+        vec![vec![0, 1, 2]]
+    );
+    assert_eq!(find_k_sum_indices(&[4, 8, 4], 1, 4), vec![vec![0]]);
 }
 
 #[test]
-fn sample_data_for_gold_solution_has_ok_result_of() {
-    let mut challenge = Day01::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_gold();
+fn entries_are_inspected() {
+    let challenge = Day01::new("1721\n979\n2500\n1721").unwrap();
 
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
+    assert_eq!(
+        challenge.inspect(),
+        vec![
+            ("Entries", "4".into()),
+            ("Distinct entries", "3".into()),
+            ("Target", "2020".into()),
+            ("Entries above target", "1".into()),
+        ]
+    );
 }
@@ -1,39 +1,251 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+mod policy;
+
+use crate::generator::SeededRng;
+use crate::{ChallengeInput, GoldChallenge, InputGenerator, Inspect, Report, SilverChallenge};
+use std::collections::BTreeSet;
+
+pub use policy::{
+    CountPolicy, PasswordPolicy, PolicyExpression, PolicyViolation, PositionPolicy, COUNT_POLICY,
+    POSITION_POLICY,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day02 {
-    data: String,
+    entries: Vec<PasswordEntry>,
 }
 
 impl Day02 {
     pub fn new(data: &str) -> Result<Self, String> {
-        Ok(Self { data: data.into() })
+        let entries = data
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                PasswordEntry::parse(line).ok_or_else(|| {
+                    format!("Invalid password entry on line {}: {:?}", index + 1, line)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { entries })
+    }
+
+    pub fn entries(&self) -> &[PasswordEntry] {
+        &self.entries
+    }
+
+    pub fn count_valid<P: PasswordPolicy + ?Sized>(&self, policy: &P) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| policy.is_valid(entry))
+            .count()
+    }
+
+    // the verdict of both puzzle policies on every line, to audit which passwords fail and why
+    pub fn line_reports(&self) -> Vec<LineReport> {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| LineReport {
+                line: index + 1,
+                entry: entry.clone(),
+                silver: CountPolicy.check(entry),
+                gold: PositionPolicy.check(entry),
+            })
+            .collect()
+    }
+}
+
+impl ChallengeInput for Day02 {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineReport {
+    pub line: usize,
+    pub entry: PasswordEntry,
+    pub silver: Result<(), PolicyViolation>,
+    pub gold: Result<(), PolicyViolation>,
+}
+
+// A line of the password database, the policy in effect when the password was set
+// along with the password itself. What `low` and `high` mean depends on the policy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordEntry {
+    pub low: usize,
+    pub high: usize,
+    pub letter: char,
+    pub password: String,
+}
+
+impl PasswordEntry {
+    // parses a line such as `1-3 a: abcde`
+    fn parse(line: &str) -> Option<Self> {
+        let (policy, password) = line.split_once(": ")?;
+        let (range, letter) = policy.split_once(' ')?;
+        let (low, high) = range.split_once('-')?;
+
+        let mut letters = letter.chars();
+        let letter = match (letters.next(), letters.next()) {
+            (Some(letter), None) => letter,
+            _ => return None,
+        };
+
+        Some(Self {
+            low: low.parse().ok()?,
+            high: high.parse().ok()?,
+            letter,
+            password: password.into(),
+        })
+    }
+
+    pub fn letter_count(&self) -> usize {
+        self.password.matches(self.letter).count()
+    }
+
+    // positions count from 1, and there is no letter past either end of the password
+    pub fn letter_at(&self, position: usize) -> Option<char> {
+        position
+            .checked_sub(1)
+            .and_then(|index| self.password.chars().nth(index))
+    }
+}
+
+impl std::fmt::Display for PasswordEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-{} {}: {}",
+            self.low, self.high, self.letter, self.password
+        )
+    }
+}
+
+impl Inspect for Day02 {
+    fn inspect(&self) -> Vec<(&'static str, String)> {
+        let letters = self
+            .entries
+            .iter()
+            .map(|entry| entry.letter)
+            .collect::<BTreeSet<_>>();
+        let lengths = self
+            .entries
+            .iter()
+            .map(|entry| entry.password.chars().count());
+        let policy_high = self.entries.iter().map(|entry| entry.high).max();
+
+        vec![
+            ("Password entries", self.entries.len().to_string()),
+            ("Policy letters", letters.into_iter().collect()),
+            (
+                "Password lengths",
+                match (lengths.clone().min(), lengths.max()) {
+                    (Some(shortest), Some(longest)) => format!("{} to {}", shortest, longest),
+                    _ => "none".into(),
+                },
+            ),
+            (
+                "Highest policy number",
+                policy_high
+                    .map(|high| high.to_string())
+                    .unwrap_or_else(|| "none".into()),
+            ),
+        ]
+    }
+}
+
+impl std::fmt::Display for Day02 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines = self
+            .entries
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        write!(f, "{}", lines.join("\n"))
     }
 }
 
 impl SilverChallenge for Day02 {
-    type Answer = ();
-    type Error = &'static str;
+    type Answer = usize;
+    type Error = String;
 
     fn attempt_silver(&mut self) -> Result<Self::Answer, Self::Error>
     where
         Self::Answer: std::fmt::Debug,
     {
-        Err("NYI")
+        Ok(self.count_valid(&CountPolicy))
     }
 }
 
 impl GoldChallenge for Day02 {
-    type Answer = ();
-    type Error = &'static str;
+    type Answer = usize;
+    type Error = String;
 
     fn attempt_gold(&mut self) -> Result<Self::Answer, Self::Error>
     where
         Self::Answer: std::fmt::Debug,
     {
-        Err("NYI")
+        Ok(self.count_valid(&PositionPolicy))
+    }
+}
+
+// lists only the lines failing a policy, as most lines in a long input pass one
+impl Report for Day02 {
+    fn report(&self) -> Vec<String> {
+        let failures = self
+            .line_reports()
+            .into_iter()
+            .filter_map(|report| {
+                let violations = [("silver", &report.silver), ("gold", &report.gold)]
+                    .iter()
+                    .filter_map(|(part, verdict)| {
+                        verdict
+                            .as_ref()
+                            .err()
+                            .map(|violation| format!("{}: {}", part, violation))
+                    })
+                    .collect::<Vec<_>>();
+                match violations.is_empty() {
+                    true => None,
+                    false => Some(format!(
+                        "Line {} `{}` fails {}",
+                        report.line,
+                        report.entry,
+                        violations.join("; ")
+                    )),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        match failures.is_empty() {
+            true => vec!["Every line passes both policies".into()],
+            false => failures,
+        }
+    }
+}
+
+impl InputGenerator for Day02 {
+    fn generate_input(size: usize, seed: u64) -> String {
+        let mut rng = SeededRng::new(seed);
+
+        (0..size)
+            .map(|_| {
+                let letter = rng.lowercase_letter();
+                let length = rng.range(3..=20);
+                let low = rng.range(1..=length - 1);
+                let high = rng.range(low + 1..=length);
+
+                // bias towards the policy letter so that a fair share of lines are valid
+                let password = (0..length)
+                    .map(|_| match rng.chance(1, 3) {
+                        true => letter,
+                        false => rng.lowercase_letter(),
+                    })
+                    .collect::<String>();
+
+                format!("{}-{} {}: {}", low, high, letter, password)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 }
@@ -0,0 +1,541 @@
+#[cfg(test)]
+mod tests;
+
+use super::PasswordEntry;
+
+// Decides whether a password was allowed by the policy in effect when it was set,
+// and if not, why it was rejected.
+pub trait PasswordPolicy {
+    fn check(&self, entry: &PasswordEntry) -> Result<(), PolicyViolation>;
+
+    fn is_valid(&self, entry: &PasswordEntry) -> bool {
+        self.check(entry).is_ok()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolicyViolation {
+    CountOutOfRange {
+        count: usize,
+        low: usize,
+        high: usize,
+    },
+    BothPositionsMatch {
+        first: usize,
+        second: usize,
+    },
+    NeitherPositionMatches {
+        first: usize,
+        second: usize,
+    },
+    PositionOutOfRange {
+        position: usize,
+        length: usize,
+    },
+    ExpressionNotSatisfied(String),
+}
+
+impl std::fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolicyViolation::CountOutOfRange { count, low, high } => {
+                write!(f, "character count {} not in {}..={}", count, low, high)
+            }
+            PolicyViolation::BothPositionsMatch { first, second } => {
+                write!(f, "both positions {} and {} match", first, second)
+            }
+            PolicyViolation::NeitherPositionMatches { first, second } => {
+                write!(f, "neither position {} nor {} matches", first, second)
+            }
+            PolicyViolation::PositionOutOfRange { position, length } => write!(
+                f,
+                "position {} out of range for password of length {}",
+                position, length
+            ),
+            PolicyViolation::ExpressionNotSatisfied(expression) => {
+                write!(f, "`{}` not satisfied", expression)
+            }
+        }
+    }
+}
+
+// silver, the letter must appear between `low` and `high` times
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CountPolicy;
+
+impl PasswordPolicy for CountPolicy {
+    fn check(&self, entry: &PasswordEntry) -> Result<(), PolicyViolation> {
+        let count = entry.letter_count();
+        match entry.low <= count && count <= entry.high {
+            true => Ok(()),
+            false => Err(PolicyViolation::CountOutOfRange {
+                count,
+                low: entry.low,
+                high: entry.high,
+            }),
+        }
+    }
+}
+
+// gold, exactly one of the positions `low` and `high` must hold the letter
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PositionPolicy;
+
+impl PasswordPolicy for PositionPolicy {
+    fn check(&self, entry: &PasswordEntry) -> Result<(), PolicyViolation> {
+        let (first, second) = (entry.low, entry.high);
+        let holds_letter = |position| entry.letter_at(position) == Some(entry.letter);
+        match (holds_letter(first), holds_letter(second)) {
+            (true, false) | (false, true) => Ok(()),
+            (true, true) => Err(PolicyViolation::BothPositionsMatch { first, second }),
+            // a position past the password explains the mismatch better than it not matching
+            (false, false) => Err([first, second]
+                .iter()
+                .copied()
+                .find(|position| entry.letter_at(*position).is_none())
+                .map(|position| PolicyViolation::PositionOutOfRange {
+                    position,
+                    length: entry.password.chars().count(),
+                })
+                .unwrap_or(PolicyViolation::NeitherPositionMatches { first, second })),
+        }
+    }
+}
+
+// the built in policies written as expressions
+pub const COUNT_POLICY: &str = "count(letter) in low..=high";
+pub const POSITION_POLICY: &str = "pos(low) == letter xor pos(high) == letter";
+
+// A policy written in a small expression language, evaluated against each entry.
+//
+// Conditions combine with `or`, `and`, `xor` (from loosest to tightest) and `not`,
+// and compare values with `==`, `!=`, `<`, `<=`, `>`, `>=`, or `in` a range such as
+// `1..=3` or `1..4`. Values are numbers, letters (`a` or `'a'`), the entry's `low`,
+// `high`, `letter` and password length `len`, along with `count(<letter>)` for how
+// often a letter appears and `pos(<number>)` for the letter at a position from 1.
+//
+// `count(a) in 1..=3 and pos(1) == a xor pos(3) == a`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyExpression {
+    source: String,
+    condition: Condition,
+}
+
+impl PolicyExpression {
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let mut parser = Parser {
+            tokens: tokenize(expression)?,
+            position: 0,
+        };
+        let condition = parser.parse_or()?;
+        match parser.peek() {
+            None => Ok(PolicyExpression {
+                source: expression.trim().into(),
+                condition,
+            }),
+            Some(token) => Err(format!("Unexpected {} after the policy.", token)),
+        }
+    }
+}
+
+impl std::str::FromStr for PolicyExpression {
+    type Err = String;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        PolicyExpression::parse(expression)
+    }
+}
+
+impl PasswordPolicy for PolicyExpression {
+    fn check(&self, entry: &PasswordEntry) -> Result<(), PolicyViolation> {
+        match self.condition.evaluate(entry) {
+            true => Ok(()),
+            false => Err(PolicyViolation::ExpressionNotSatisfied(self.source.clone())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CompareOperator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl CompareOperator {
+    fn compare<T: Ord>(self, left: T, right: T) -> bool {
+        match self {
+            CompareOperator::Equal => left == right,
+            CompareOperator::NotEqual => left != right,
+            CompareOperator::Less => left < right,
+            CompareOperator::LessOrEqual => left <= right,
+            CompareOperator::Greater => left > right,
+            CompareOperator::GreaterOrEqual => left >= right,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(usize),
+    Name(String),
+    Letter(char),
+    Open,
+    Close,
+    Compare(CompareOperator),
+    Range { inclusive: bool },
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Number(number) => write!(f, "`{}`", number),
+            Token::Name(name) => write!(f, "`{}`", name),
+            Token::Letter(letter) => write!(f, "`'{}'`", letter),
+            Token::Open => write!(f, "`(`"),
+            Token::Close => write!(f, "`)`"),
+            Token::Compare(_) => write!(f, "comparison"),
+            Token::Range { .. } => write!(f, "range"),
+        }
+    }
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut characters = expression.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        let token = match character {
+            _ if character.is_whitespace() => continue,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '0'..='9' => {
+                let mut digits = character.to_string();
+                while let Some(digit) = characters.next_if(char::is_ascii_digit) {
+                    digits.push(digit);
+                }
+                let number = digits
+                    .parse()
+                    .map_err(|_| format!("Number {} is too large.", digits))?;
+                Token::Number(number)
+            }
+            '\'' => match (characters.next(), characters.next()) {
+                (Some(letter), Some('\'')) => Token::Letter(letter),
+                _ => return Err("Letters in quotes must be a single character, like 'a'.".into()),
+            },
+            '.' => match (characters.next(), characters.next_if_eq(&'=')) {
+                (Some('.'), Some(_)) => Token::Range { inclusive: true },
+                (Some('.'), None) => Token::Range { inclusive: false },
+                _ => return Err("Expected `..` or `..=` for a range.".into()),
+            },
+            '=' | '!' | '<' | '>' => {
+                let followed_by_equals = characters.next_if_eq(&'=').is_some();
+                Token::Compare(match (character, followed_by_equals) {
+                    ('=', true) => CompareOperator::Equal,
+                    ('!', true) => CompareOperator::NotEqual,
+                    ('<', false) => CompareOperator::Less,
+                    ('<', true) => CompareOperator::LessOrEqual,
+                    ('>', false) => CompareOperator::Greater,
+                    ('>', true) => CompareOperator::GreaterOrEqual,
+                    _ => return Err(format!("Expected `{}=`.", character)),
+                })
+            }
+            _ if character.is_alphabetic() => {
+                let mut name = character.to_string();
+                while let Some(letter) = characters.next_if(|c| c.is_alphanumeric() || *c == '_') {
+                    name.push(letter);
+                }
+                // a lone letter is a letter, anything longer is a name
+                match name.chars().count() {
+                    1 => Token::Letter(character),
+                    _ => Token::Name(name),
+                }
+            }
+            _ => return Err(format!("Unexpected character `{}`.", character)),
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ValueType {
+    Number,
+    Letter,
+}
+
+impl std::fmt::Display for ValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValueType::Number => write!(f, "a number"),
+            ValueType::Letter => write!(f, "a letter"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Number(usize),
+    Letter(char),
+    Low,
+    High,
+    PolicyLetter,
+    Length,
+    Count(Box<Value>),
+    Position(Box<Value>),
+}
+
+// a position past the end of the password holds no letter at all
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Evaluated {
+    Number(usize),
+    Letter(Option<char>),
+}
+
+impl Value {
+    fn value_type(&self) -> ValueType {
+        match self {
+            Value::Number(_) | Value::Low | Value::High | Value::Length | Value::Count(_) => {
+                ValueType::Number
+            }
+            Value::Letter(_) | Value::PolicyLetter | Value::Position(_) => ValueType::Letter,
+        }
+    }
+
+    fn evaluate(&self, entry: &PasswordEntry) -> Evaluated {
+        match self {
+            Value::Number(number) => Evaluated::Number(*number),
+            Value::Letter(letter) => Evaluated::Letter(Some(*letter)),
+            Value::Low => Evaluated::Number(entry.low),
+            Value::High => Evaluated::Number(entry.high),
+            Value::PolicyLetter => Evaluated::Letter(Some(entry.letter)),
+            Value::Length => Evaluated::Number(entry.password.chars().count()),
+            Value::Count(letter) => Evaluated::Number(match letter.evaluate(entry) {
+                Evaluated::Letter(Some(letter)) => entry.password.matches(letter).count(),
+                _ => 0,
+            }),
+            Value::Position(position) => Evaluated::Letter(match position.evaluate(entry) {
+                Evaluated::Number(position) => entry.letter_at(position),
+                Evaluated::Letter(_) => None,
+            }),
+        }
+    }
+
+    // values were type checked when parsed, so only numbers are ever given here
+    fn evaluate_number(&self, entry: &PasswordEntry) -> usize {
+        match self.evaluate(entry) {
+            Evaluated::Number(number) => number,
+            Evaluated::Letter(_) => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Condition {
+    Constant(bool),
+    Not(Box<Condition>),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+    Xor(Box<Condition>, Box<Condition>),
+    Compare(Value, CompareOperator, Value),
+    InRange {
+        value: Value,
+        low: Value,
+        high: Value,
+        inclusive: bool,
+    },
+}
+
+impl Condition {
+    fn evaluate(&self, entry: &PasswordEntry) -> bool {
+        match self {
+            Condition::Constant(constant) => *constant,
+            Condition::Not(condition) => !condition.evaluate(entry),
+            Condition::And(left, right) => left.evaluate(entry) && right.evaluate(entry),
+            Condition::Or(left, right) => left.evaluate(entry) || right.evaluate(entry),
+            Condition::Xor(left, right) => left.evaluate(entry) != right.evaluate(entry),
+            Condition::Compare(left, operator, right) => {
+                match (left.evaluate(entry), right.evaluate(entry)) {
+                    (Evaluated::Number(left), Evaluated::Number(right)) => {
+                        operator.compare(left, right)
+                    }
+                    (Evaluated::Letter(Some(left)), Evaluated::Letter(Some(right))) => {
+                        operator.compare(left, right)
+                    }
+                    // a missing letter is not equal to any letter, but neither before nor after it
+                    _ => *operator == CompareOperator::NotEqual,
+                }
+            }
+            Condition::InRange {
+                value,
+                low,
+                high,
+                inclusive,
+            } => {
+                let value = value.evaluate_number(entry);
+                let (low, high) = (low.evaluate_number(entry), high.evaluate_number(entry));
+                match inclusive {
+                    true => (low..=high).contains(&value),
+                    false => (low..high).contains(&value),
+                }
+            }
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Result<Token, String> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .ok_or_else(|| "Unexpected end of the policy.".to_string())?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn next_if_name(&mut self, name: &str) -> bool {
+        match self.peek() {
+            Some(Token::Name(next)) if next == name => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), String> {
+        match self.next()? {
+            token if token == expected => Ok(()),
+            token => Err(format!("Expected {} but found {}.", expected, token)),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Condition, String> {
+        let mut condition = self.parse_and()?;
+        while self.next_if_name("or") {
+            condition = Condition::Or(Box::new(condition), Box::new(self.parse_and()?));
+        }
+        Ok(condition)
+    }
+
+    fn parse_and(&mut self) -> Result<Condition, String> {
+        let mut condition = self.parse_xor()?;
+        while self.next_if_name("and") {
+            condition = Condition::And(Box::new(condition), Box::new(self.parse_xor()?));
+        }
+        Ok(condition)
+    }
+
+    fn parse_xor(&mut self) -> Result<Condition, String> {
+        let mut condition = self.parse_not()?;
+        while self.next_if_name("xor") {
+            condition = Condition::Xor(Box::new(condition), Box::new(self.parse_not()?));
+        }
+        Ok(condition)
+    }
+
+    fn parse_not(&mut self) -> Result<Condition, String> {
+        match self.next_if_name("not") {
+            true => Ok(Condition::Not(Box::new(self.parse_not()?))),
+            false => self.parse_condition(),
+        }
+    }
+
+    fn parse_condition(&mut self) -> Result<Condition, String> {
+        if self.peek() == Some(&Token::Open) {
+            self.position += 1;
+            let condition = self.parse_or()?;
+            self.expect(Token::Close)?;
+            return Ok(condition);
+        }
+        if self.next_if_name("true") {
+            return Ok(Condition::Constant(true));
+        }
+        if self.next_if_name("false") {
+            return Ok(Condition::Constant(false));
+        }
+
+        let value = self.parse_value()?;
+        if self.next_if_name("in") {
+            let low = self.parse_number()?;
+            let inclusive = match self.next()? {
+                Token::Range { inclusive } => inclusive,
+                token => return Err(format!("Expected range but found {}.", token)),
+            };
+            let high = self.parse_number()?;
+            return match value.value_type() {
+                ValueType::Number => Ok(Condition::InRange {
+                    value,
+                    low,
+                    high,
+                    inclusive,
+                }),
+                ValueType::Letter => Err("Only numbers can be in a range.".into()),
+            };
+        }
+
+        let operator = match self.next()? {
+            Token::Compare(operator) => operator,
+            token => return Err(format!("Expected comparison but found {}.", token)),
+        };
+        let other_value = self.parse_value()?;
+        match (value.value_type(), other_value.value_type()) {
+            (left, right) if left == right => Ok(Condition::Compare(value, operator, other_value)),
+            (left, right) => Err(format!("Cannot compare {} with {}.", left, right)),
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<Value, String> {
+        let value = self.parse_value()?;
+        match value.value_type() {
+            ValueType::Number => Ok(value),
+            value_type => Err(format!("Expected a number but found {}.", value_type)),
+        }
+    }
+
+    fn parse_letter(&mut self) -> Result<Value, String> {
+        let value = self.parse_value()?;
+        match value.value_type() {
+            ValueType::Letter => Ok(value),
+            value_type => Err(format!("Expected a letter but found {}.", value_type)),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, String> {
+        match self.next()? {
+            Token::Number(number) => Ok(Value::Number(number)),
+            Token::Letter(letter) => Ok(Value::Letter(letter)),
+            Token::Name(name) => match name.as_str() {
+                "low" => Ok(Value::Low),
+                "high" => Ok(Value::High),
+                "letter" => Ok(Value::PolicyLetter),
+                "len" => Ok(Value::Length),
+                "count" | "pos" => {
+                    self.expect(Token::Open)?;
+                    let value = match name.as_str() {
+                        "count" => Value::Count(Box::new(self.parse_letter()?)),
+                        _ => Value::Position(Box::new(self.parse_number()?)),
+                    };
+                    self.expect(Token::Close)?;
+                    Ok(value)
+                }
+                _ => Err(format!("Unknown name `{}`.", name)),
+            },
+            token => Err(format!("Expected a value but found {}.", token)),
+        }
+    }
+}
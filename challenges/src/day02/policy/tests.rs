@@ -0,0 +1,129 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+fn entry(line: &str) -> PasswordEntry {
+    PasswordEntry::parse(line).unwrap()
+}
+
+fn evaluate(expression: &str, line: &str) -> bool {
+    PolicyExpression::parse(expression)
+        .unwrap()
+        .is_valid(&entry(line))
+}
+
+#[test]
+fn count_policy_checks_letter_count_in_range() {
+    assert!(CountPolicy.is_valid(&entry("1-3 a: abcde")));
+    assert!(!CountPolicy.is_valid(&entry("1-3 b: cdefg")));
+    assert!(CountPolicy.is_valid(&entry("2-9 c: ccccccccc")));
+}
+
+#[test]
+fn position_policy_checks_exactly_one_position() {
+    assert!(PositionPolicy.is_valid(&entry("1-3 a: abcde")));
+    assert!(!PositionPolicy.is_valid(&entry("1-3 b: cdefg")));
+    assert!(!PositionPolicy.is_valid(&entry("2-9 c: ccccccccc")));
+    assert!(PositionPolicy.is_valid(&entry("1-30 a: abc")));
+}
+
+#[test]
+fn expression_with_literal_values() {
+    let expression = "count(a) in 1..=3 and pos(1) == a xor pos(3) == a";
+
+    assert!(evaluate(expression, "9-9 z: abcde"));
+    assert!(!evaluate(expression, "9-9 z: abade"));
+    assert!(!evaluate(expression, "9-9 z: bcdef"));
+}
+
+#[test]
+fn expression_with_entry_values() {
+    assert!(evaluate(COUNT_POLICY, "1-3 a: abcde"));
+    assert!(!evaluate(COUNT_POLICY, "1-3 b: cdefg"));
+    assert!(evaluate(POSITION_POLICY, "1-3 a: abcde"));
+    assert!(!evaluate(POSITION_POLICY, "2-9 c: ccccccccc"));
+}
+
+#[test]
+fn xor_binds_tighter_than_and_which_binds_tighter_than_or() {
+    assert!(evaluate("true or false and false", "1-1 a: a"));
+    assert!(evaluate("true and true xor false", "1-1 a: a"));
+    assert!(!evaluate("(true or false) and false", "1-1 a: a"));
+    assert!(evaluate("not false and not (true xor true)", "1-1 a: a"));
+}
+
+#[test]
+fn comparisons_and_ranges() {
+    assert!(evaluate(
+        "len >= 5 and len < 6 and low != high",
+        "1-3 a: abcde"
+    ));
+    assert!(evaluate(
+        "high in low..4 and not high in low..3",
+        "1-3 a: abcde"
+    ));
+    assert!(evaluate(
+        "pos(2) > letter and 'b' == pos(2)",
+        "1-3 a: abcde"
+    ));
+}
+
+#[test]
+fn positions_past_the_password_hold_no_letter() {
+    assert!(!evaluate("pos(9) == a", "1-3 a: abc"));
+    assert!(evaluate("pos(9) != a", "1-3 a: abc"));
+    assert!(!evaluate("pos(0) <= a or pos(0) > a", "1-3 a: abc"));
+}
+
+#[test]
+fn invalid_expressions_are_errors() {
+    let error = |expression| PolicyExpression::parse(expression).unwrap_err();
+
+    assert_eq!(
+        error("count(a) in 1..=3 and"),
+        "Unexpected end of the policy."
+    );
+    assert_eq!(
+        error("count(1) > 0"),
+        "Expected a letter but found a number."
+    );
+    assert_eq!(
+        error("pos(1) == 3"),
+        "Cannot compare a letter with a number."
+    );
+    assert_eq!(error("letter in 1..3"), "Only numbers can be in a range.");
+    assert_eq!(error("length > 3"), "Unknown name `length`.");
+    assert_eq!(error("len > 3 len"), "Unexpected `len` after the policy.");
+    assert_eq!(error("len = 3"), "Expected `==`.");
+    assert_eq!(error("(len > 3"), "Unexpected end of the policy.");
+    assert_eq!(error("len # 3"), "Unexpected character `#`.");
+}
+
+#[test]
+fn violations_explain_why_entries_fail() {
+    let check = |policy: &dyn PasswordPolicy, line| policy.check(&entry(line)).unwrap_err();
+
+    assert_eq!(
+        check(&CountPolicy, "1-3 b: cdefg").to_string(),
+        "character count 0 not in 1..=3"
+    );
+    assert_eq!(
+        check(&PositionPolicy, "2-9 c: ccccccccc").to_string(),
+        "both positions 2 and 9 match"
+    );
+    assert_eq!(
+        check(&PositionPolicy, "1-3 b: cdefg").to_string(),
+        "neither position 1 nor 3 matches"
+    );
+    assert_eq!(
+        check(&PositionPolicy, "2-9 a: abc").to_string(),
+        "position 9 out of range for password of length 3"
+    );
+    assert_eq!(
+        check(
+            &PolicyExpression::parse(" len > 5 ").unwrap(),
+            "1-3 b: cdefg"
+        )
+        .to_string(),
+        "`len > 5` not satisfied"
+    );
+}
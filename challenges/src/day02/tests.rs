@@ -1,44 +1,162 @@
 // import everything from the parent module file (in this case mod.rs)
 use super::*;
 
-const SAMPLE_DATA: &'static str = "<replace this with sample data from challenge>";
+const SAMPLE_DATA: &str = "1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc";
+
+sample_tests!(Day02, [
+    { data: SAMPLE_DATA, silver: Ok(2), gold: Ok(1) },
+    { data: "1-3 a: abcde", silver: Ok(1), gold: Ok(1) },
+    { data: "2-9 c: ccccccccc", silver: Ok(1), gold: Ok(0) },
+], input: { file: "day02.txt", silver: Ok(458), gold: Ok(342) });
+
+round_trip_property!(Day02, crate::properties::password_entries());
+
+impl Day02 {
+    fn counts_by_policy(&self) -> (usize, usize) {
+        (
+            self.count_valid(&CountPolicy),
+            self.count_valid(&PositionPolicy),
+        )
+    }
+
+    fn counts_by_expression(&self) -> (usize, usize) {
+        (
+            self.count_valid(&PolicyExpression::parse(COUNT_POLICY).unwrap()),
+            self.count_valid(&PolicyExpression::parse(POSITION_POLICY).unwrap()),
+        )
+    }
+}
+
+equivalent_variants_property!(
+    Day02,
+    crate::properties::password_entries(),
+    counts_by_policy,
+    counts_by_expression
+);
+
+#[test]
+fn entries_are_parsed() {
+    let challenge = Day02::new("1-3 a: abcde").unwrap();
+
+    assert_eq!(
+        challenge.entries(),
+        [PasswordEntry {
+            low: 1,
+            high: 3,
+            letter: 'a',
+            password: "abcde".into(),
+        }]
+    );
+}
+
+#[test]
+fn invalid_entry_is_reported_with_line() {
+    assert_eq!(
+        Day02::new("1-3 a: abcde\n1-3 ab: cdefg"),
+        Err("Invalid password entry on line 2: \"1-3 ab: cdefg\"".into())
+    );
+    assert_eq!(
+        Day02::new("1 a: abcde"),
+        Err("Invalid password entry on line 1: \"1 a: abcde\"".into())
+    );
+}
+
+#[test]
+fn positions_past_the_password_do_not_match() {
+    let mut challenge = Day02::new("1-9 a: abc\n0-2 b: abc").unwrap();
+
+    assert_eq!(challenge.attempt_gold(), Ok(2));
+}
+
+#[test]
+fn other_policies_can_be_evaluated() {
+    let challenge = Day02::new(SAMPLE_DATA).unwrap();
+    let policy = PolicyExpression::parse("len > 5 or count(letter) == 0").unwrap();
+
+    assert_eq!(challenge.count_valid(&policy), 2);
+}
 
 #[test]
-fn sample_data_builds_ok() {
+fn generated_data_builds_ok() {
+    let data = Day02::generate_input(1_000, 2020);
+
     // unwrap will fail the test here if the day input parsing returned an Err
-    Day02::new(SAMPLE_DATA).unwrap();
+    Day02::new(&data).unwrap();
+}
+
+#[test]
+fn generated_data_is_reproducible_from_seed() {
+    assert_eq!(Day02::generate_input(100, 7), Day02::generate_input(100, 7));
+    assert_ne!(Day02::generate_input(100, 7), Day02::generate_input(100, 8));
+}
+
+#[test]
+fn generated_data_has_requested_number_of_lines() {
+    assert_eq!(Day02::generate_input(250, 2020).lines().count(), 250);
 }
 
 #[test]
-fn sample_data_for_silver_solution_has_ok_result() {
-    let mut challenge = Day02::new(SAMPLE_DATA).unwrap();
+fn line_reports_give_both_verdicts_per_line() {
+    let challenge = Day02::new(SAMPLE_DATA).unwrap();
+    let reports = challenge.line_reports();
 
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_silver().unwrap();
+    assert_eq!(reports.len(), 3);
+    assert_eq!(reports[0].line, 1);
+    assert_eq!((&reports[0].silver, &reports[0].gold), (&Ok(()), &Ok(())));
+    assert_eq!(
+        reports[1].silver,
+        Err(PolicyViolation::CountOutOfRange {
+            count: 0,
+            low: 1,
+            high: 3
+        })
+    );
+    assert_eq!(
+        reports[2].gold,
+        Err(PolicyViolation::BothPositionsMatch {
+            first: 2,
+            second: 9
+        })
+    );
 }
 
 #[test]
-fn sample_data_for_silver_solution_has_ok_result_of() {
-    let mut challenge = Day02::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_silver();
+fn report_lists_failing_lines() {
+    let challenge = Day02::new(SAMPLE_DATA).unwrap();
 
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
+    assert_eq!(
+        Report::report(&challenge),
+        vec![
+            "Line 2 `1-3 b: cdefg` fails silver: character count 0 not in 1..=3; \
+             gold: neither position 1 nor 3 matches",
+            "Line 3 `2-9 c: ccccccccc` fails gold: both positions 2 and 9 match",
+        ]
+    );
 }
 
 #[test]
-fn sample_data_for_gold_solution_has_ok_result() {
-    let mut challenge = Day02::new(SAMPLE_DATA).unwrap();
+fn report_of_all_valid_lines() {
+    let challenge = Day02::new("1-3 a: abcde").unwrap();
 
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_gold().unwrap();
+    assert_eq!(
+        Report::report(&challenge),
+        vec!["Every line passes both policies"]
+    );
 }
 
 #[test]
-fn sample_data_for_gold_solution_has_ok_result_of() {
-    let mut challenge = Day02::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_gold();
+fn entries_are_inspected() {
+    let challenge = Day02::new(SAMPLE_DATA).unwrap();
 
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
+    assert_eq!(
+        challenge.inspect(),
+        vec![
+            ("Password entries", "3".into()),
+            ("Policy letters", "abc".into()),
+            ("Password lengths", "5 to 9".into()),
+            ("Highest policy number", "9".into()),
+        ]
+    );
 }
@@ -1,39 +1,295 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+mod render;
+
+use crate::generator::SeededRng;
+use crate::{ChallengeInput, GoldChallenge, InputGenerator, Inspect, Report, SilverChallenge};
+
+pub use render::{PathRender, Square};
+
+// silver only follows right 3, down 1, while gold multiplies the trees of all five
+pub const SILVER_SLOPE: Slope = Slope { right: 3, down: 1 };
+pub const GOLD_SLOPES: [Slope; 5] = [
+    Slope { right: 1, down: 1 },
+    Slope { right: 3, down: 1 },
+    Slope { right: 5, down: 1 },
+    Slope { right: 7, down: 1 },
+    Slope { right: 1, down: 2 },
+];
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day03 {
-    data: String,
+    map: TreeMap,
 }
 
 impl Day03 {
     pub fn new(data: &str) -> Result<Self, String> {
-        Ok(Self { data: data.into() })
+        Ok(Self {
+            map: TreeMap::parse(data)?,
+        })
+    }
+
+    pub fn map(&self) -> &TreeMap {
+        &self.map
+    }
+}
+
+impl ChallengeInput for Day03 {}
+
+impl std::fmt::Display for Day03 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+// How far the toboggan moves with each step. It always moves down, otherwise it
+// would never reach the bottom of the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Slope {
+    right: usize,
+    down: usize,
+}
+
+impl Slope {
+    pub fn new(right: usize, down: usize) -> Result<Self, String> {
+        match down {
+            0 => Err(format!(
+                "Slope right {}, down 0 never reaches the bottom of the map.",
+                right
+            )),
+            _ => Ok(Self { right, down }),
+        }
+    }
+
+    pub fn right(&self) -> usize {
+        self.right
+    }
+
+    pub fn down(&self) -> usize {
+        self.down
+    }
+}
+
+impl std::fmt::Display for Slope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "right {}, down {}", self.right, self.down)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlopeTrees {
+    pub slope: Slope,
+    pub trees: usize,
+}
+
+// The open squares and trees of the area, where the pattern of each row repeats
+// to the right forever.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeMap {
+    width: usize,
+    rows: Vec<Vec<bool>>,
+}
+
+impl TreeMap {
+    pub fn parse(data: &str) -> Result<Self, String> {
+        let rows = data
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(column, square)| match square {
+                        '.' => Ok(false),
+                        '#' => Ok(true),
+                        _ => Err(format!(
+                            "Invalid map square on line {} column {}: {:?}",
+                            index + 1,
+                            column + 1,
+                            square
+                        )),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let width = rows.first().map(Vec::len).unwrap_or(0);
+        if width == 0 {
+            return Err("Map has no squares.".into());
+        }
+        if let Some(index) = rows.iter().position(|row| row.len() != width) {
+            return Err(format!(
+                "Line {} of the map is {} squares wide, expected {}.",
+                index + 1,
+                rows[index].len(),
+                width
+            ));
+        }
+
+        Ok(Self { width, rows })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    // columns wrap around as the map repeats, but there is nothing below the last row
+    pub fn is_tree(&self, column: usize, row: usize) -> Option<bool> {
+        self.rows
+            .get(row)
+            .map(|squares| squares[column % self.width])
+    }
+
+    // The squares visited from the top left corner until going past the bottom,
+    // as the column and row of each. Columns wrap around the width of the map at
+    // each step, so that the running column of a huge slope never overflows.
+    pub fn path(&self, slope: Slope) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let width = self.width;
+        (0..self.height())
+            .step_by(slope.down)
+            .scan(None, move |column: &mut Option<usize>, row| {
+                let next = match *column {
+                    Some(column) => (column + slope.right % width) % width,
+                    None => 0,
+                };
+                *column = Some(next);
+                Some((next, row))
+            })
+    }
+
+    // the starting square is where the toboggan sets off from, so is not checked
+    pub fn count_trees(&self, slope: Slope) -> usize {
+        self.path(slope)
+            .skip(1)
+            .filter(|(column, row)| self.is_tree(*column, *row) == Some(true))
+            .count()
+    }
+
+    // Counts the trees of every slope moving at most `max_right` and `max_down`
+    // each step, ranked from fewest trees to most. Ties are ranked by `down` and
+    // then by `right`, smallest first.
+    pub fn rank_slopes(&self, max_right: usize, max_down: usize) -> Vec<SlopeTrees> {
+        let mut ranked = (1..=max_down)
+            .flat_map(|down| (0..=max_right).map(move |right| Slope { right, down }))
+            .map(|slope| SlopeTrees {
+                slope,
+                trees: self.count_trees(slope),
+            })
+            .collect::<Vec<_>>();
+        // slopes are generated in tie order, which the stable sort keeps
+        ranked.sort_by_key(|ranking| ranking.trees);
+        ranked
+    }
+
+    pub fn best_slope(&self, max_right: usize, max_down: usize) -> Result<SlopeTrees, String> {
+        self.rank_slopes(max_right, max_down)
+            .first()
+            .copied()
+            .ok_or_else(|| "No slopes to search, slopes must move down at least 1.".to_string())
+    }
+}
+
+// serializes the map as it was read, without repeating to the right
+impl std::fmt::Display for TreeMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|tree| if *tree { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", rows.join("\n"))
     }
 }
 
 impl SilverChallenge for Day03 {
-    type Answer = ();
-    type Error = &'static str;
+    type Answer = usize;
+    type Error = String;
 
     fn attempt_silver(&mut self) -> Result<Self::Answer, Self::Error>
     where
         Self::Answer: std::fmt::Debug,
     {
-        Err("NYI")
+        Ok(self.map.count_trees(SILVER_SLOPE))
     }
 }
 
 impl GoldChallenge for Day03 {
-    type Answer = ();
-    type Error = &'static str;
+    type Answer = usize;
+    type Error = String;
 
     fn attempt_gold(&mut self) -> Result<Self::Answer, Self::Error>
     where
         Self::Answer: std::fmt::Debug,
     {
-        Err("NYI")
+        GOLD_SLOPES
+            .iter()
+            .try_fold(1usize, |product, slope| {
+                product.checked_mul(self.map.count_trees(*slope))
+            })
+            .ok_or_else(|| "Product of tree counts overflows.".to_string())
+    }
+}
+
+// the trees on each gold slope, then the best slope no steeper than those
+impl Report for Day03 {
+    fn report(&self) -> Vec<String> {
+        let mut lines = GOLD_SLOPES
+            .iter()
+            .map(|slope| format!("{}: {} trees", slope, self.map.count_trees(*slope)))
+            .collect::<Vec<_>>();
+
+        let max_right = GOLD_SLOPES.iter().map(Slope::right).max().unwrap_or(0);
+        let max_down = GOLD_SLOPES.iter().map(Slope::down).max().unwrap_or(1);
+        if let Ok(best) = self.map.best_slope(max_right, max_down) {
+            lines.push(format!(
+                "Fewest trees up to right {}, down {}: {} with {} trees",
+                max_right, max_down, best.slope, best.trees
+            ));
+        }
+
+        lines
+    }
+}
+
+impl Inspect for Day03 {
+    fn inspect(&self) -> Vec<(&'static str, String)> {
+        let squares = self.map.width() * self.map.height();
+        let trees = self.map.rows.iter().flatten().filter(|tree| **tree).count();
+
+        vec![
+            (
+                "Map",
+                format!("{} wide by {} high", self.map.width(), self.map.height()),
+            ),
+            ("Trees", format!("{} of {} squares", trees, squares)),
+        ]
+    }
+}
+
+impl InputGenerator for Day03 {
+    fn generate_input(size: usize, seed: u64) -> String {
+        let mut rng = SeededRng::new(seed);
+
+        (0..size)
+            .map(|row| {
+                (0..31)
+                    .map(|column| match (row, column) {
+                        // the starting square is always open
+                        (0, 0) => '.',
+                        _ if rng.chance(1, 4) => '#',
+                        _ => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 }
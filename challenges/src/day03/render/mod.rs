@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests;
+
+use super::{Slope, TreeMap};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Square {
+    Open,
+    Tree,
+    OpenOnPath,
+    TreeOnPath,
+}
+
+impl Square {
+    // marked as in the puzzle text, `O` where the path checks an open square and `X` a tree
+    pub fn symbol(self) -> char {
+        match self {
+            Square::Open => '.',
+            Square::Tree => '#',
+            Square::OpenOnPath => 'O',
+            Square::TreeOnPath => 'X',
+        }
+    }
+
+    fn color(self) -> [u8; 3] {
+        match self {
+            Square::Open => [236, 240, 244],
+            Square::Tree => [34, 102, 51],
+            Square::OpenOnPath => [51, 119, 221],
+            Square::TreeOnPath => [221, 34, 34],
+        }
+    }
+}
+
+// The map repeated to the right until the whole path fits, with the squares the
+// path checks marked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathRender {
+    rows: Vec<Vec<Square>>,
+}
+
+impl PathRender {
+    pub fn rows(&self) -> &[Vec<Square>] {
+        &self.rows
+    }
+
+    // a binary PPM image, drawing each square as `scale` by `scale` pixels
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let width = self.rows.first().map(Vec::len).unwrap_or(0) * scale;
+        let height = self.rows.len() * scale;
+
+        let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        image.reserve(width * height * 3);
+        for row in &self.rows {
+            let pixel_row = row
+                .iter()
+                .flat_map(|square| std::iter::repeat_n(square.color(), scale))
+                .flatten()
+                .collect::<Vec<_>>();
+            for _ in 0..scale {
+                image.extend_from_slice(&pixel_row);
+            }
+        }
+
+        image
+    }
+}
+
+impl std::fmt::Display for PathRender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(|square| square.symbol()).collect::<String>())
+            .collect::<Vec<_>>();
+        write!(f, "{}", rows.join("\n"))
+    }
+}
+
+impl TreeMap {
+    pub fn render_path(&self, slope: Slope) -> PathRender {
+        // the starting square is where the toboggan sets off from, so is not checked,
+        // and the map repeats to the right for as far as the slope really moves
+        let checked = self
+            .path(slope)
+            .enumerate()
+            .skip(1)
+            .map(|(step, (_, row))| (step * slope.right(), row))
+            .collect::<HashSet<_>>();
+        let last_column = checked.iter().map(|(column, _)| *column).max().unwrap_or(0);
+        let width = (last_column / self.width() + 1) * self.width();
+
+        let rows = (0..self.height())
+            .map(|row| {
+                (0..width)
+                    .map(|column| {
+                        let on_path = checked.contains(&(column, row));
+                        match (self.is_tree(column, row) == Some(true), on_path) {
+                            (false, false) => Square::Open,
+                            (true, false) => Square::Tree,
+                            (false, true) => Square::OpenOnPath,
+                            (true, true) => Square::TreeOnPath,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        PathRender { rows }
+    }
+}
@@ -0,0 +1,89 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+const SAMPLE_DATA: &str = "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+#[test]
+fn path_is_marked_as_in_the_puzzle() {
+    let map = TreeMap::parse(SAMPLE_DATA).unwrap();
+
+    // the puzzle repeats the map six times, but three are enough to fit the path
+    assert_eq!(
+        map.render_path(Slope::new(3, 1).unwrap()).to_string(),
+        "..##.........##.........##.......
+#..O#...#..#...#...#..#...#...#..
+.#....X..#..#....#..#..#....#..#.
+..#.#...#O#..#.#...#.#..#.#...#.#
+.#...##..#..X...##..#..#...##..#.
+..#.##.......#.X#.......#.##.....
+.#.#.#....#.#.#.#.O..#.#.#.#....#
+.#........#.#........X.#........#
+#.##...#...#.##...#...#.X#...#...
+#...##....##...##....##...#X....#
+.#..#...#.#.#..#...#.#.#..#...X.#"
+    );
+}
+
+#[test]
+fn marked_trees_match_tree_count() {
+    let map = TreeMap::parse(SAMPLE_DATA).unwrap();
+
+    for (right, down) in [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2), (0, 1), (13, 3)].iter() {
+        let slope = Slope::new(*right, *down).unwrap();
+        let marked_trees = map
+            .render_path(slope)
+            .rows()
+            .iter()
+            .flatten()
+            .filter(|square| **square == Square::TreeOnPath)
+            .count();
+        assert_eq!(marked_trees, map.count_trees(slope), "{}", slope);
+    }
+}
+
+#[test]
+fn slopes_wider_than_the_map_are_drawn_as_they_move() {
+    let map = TreeMap::parse(SAMPLE_DATA).unwrap();
+    let render = map.render_path(Slope::new(14, 1).unwrap());
+
+    assert_eq!(render.rows()[0].len(), 11 * 13);
+    assert_ne!(render, map.render_path(Slope::new(3, 1).unwrap()));
+}
+
+#[test]
+fn vertical_path_is_not_repeated() {
+    let map = TreeMap::parse("..\n#.\n..").unwrap();
+
+    assert_eq!(
+        map.render_path(Slope::new(0, 1).unwrap()).to_string(),
+        "..\nX.\nO."
+    );
+}
+
+#[test]
+fn image_has_scaled_pixels_per_square() {
+    let map = TreeMap::parse(".#\n#.").unwrap();
+    let image = map.render_path(Slope::new(1, 1).unwrap()).to_ppm(2);
+
+    let header = b"P6\n4 4\n255\n";
+    assert_eq!(&image[..header.len()], header);
+    let pixels = &image[header.len()..];
+    assert_eq!(pixels.len(), 4 * 4 * 3);
+
+    let pixel = |x: usize, y: usize| &pixels[(y * 4 + x) * 3..(y * 4 + x) * 3 + 3];
+    assert_eq!(pixel(0, 0), Square::Open.color());
+    assert_eq!(pixel(1, 1), Square::Open.color());
+    assert_eq!(pixel(2, 0), Square::Tree.color());
+    assert_eq!(pixel(0, 3), Square::Tree.color());
+    assert_eq!(pixel(3, 3), Square::OpenOnPath.color());
+}
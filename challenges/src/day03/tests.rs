@@ -1,44 +1,185 @@
 // import everything from the parent module file (in this case mod.rs)
 use super::*;
 
-const SAMPLE_DATA: &'static str = "<replace this with sample data from challenge>";
+const SAMPLE_DATA: &str = "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+sample_tests!(Day03, [
+    { data: SAMPLE_DATA, silver: Ok(7), gold: Ok(336) },
+], input: { file: "day03.txt", silver: Ok(250), gold: Ok(1592662500) });
+
+fn slope(right: usize, down: usize) -> Slope {
+    Slope::new(right, down).unwrap()
+}
 
 #[test]
-fn sample_data_builds_ok() {
-    // unwrap will fail the test here if the day input parsing returned an Err
-    Day03::new(SAMPLE_DATA).unwrap();
+fn trees_are_counted_for_each_slope() {
+    let challenge = Day03::new(SAMPLE_DATA).unwrap();
+    let counts = GOLD_SLOPES
+        .iter()
+        .map(|slope| challenge.map().count_trees(*slope))
+        .collect::<Vec<_>>();
+
+    assert_eq!(counts, vec![2, 7, 3, 4, 2]);
+}
+
+#[test]
+fn map_wraps_to_the_right() {
+    let map = TreeMap::parse("..#\n#..").unwrap();
+
+    assert_eq!((map.width(), map.height()), (3, 2));
+    assert_eq!(map.is_tree(2, 0), Some(true));
+    assert_eq!(map.is_tree(5, 0), Some(true));
+    assert_eq!(map.is_tree(301, 1), Some(false));
+    assert_eq!(map.is_tree(0, 2), None);
 }
 
 #[test]
-fn sample_data_for_silver_solution_has_ok_result() {
-    let mut challenge = Day03::new(SAMPLE_DATA).unwrap();
+fn path_stops_past_the_bottom() {
+    let map = TreeMap::parse(SAMPLE_DATA).unwrap();
 
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_silver().unwrap();
+    assert_eq!(
+        map.path(slope(1, 2)).collect::<Vec<_>>(),
+        vec![(0, 0), (1, 2), (2, 4), (3, 6), (4, 8), (5, 10)]
+    );
+    assert_eq!(
+        map.path(slope(7, 4)).collect::<Vec<_>>(),
+        vec![(0, 0), (7, 4), (3, 8)]
+    );
+    assert_eq!(map.path(slope(0, 11)).collect::<Vec<_>>(), vec![(0, 0)]);
 }
 
 #[test]
-fn sample_data_for_silver_solution_has_ok_result_of() {
-    let mut challenge = Day03::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_silver();
+fn steep_and_vertical_slopes_are_counted() {
+    let map = TreeMap::parse(SAMPLE_DATA).unwrap();
 
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
+    // straight down the first column passes trees on lines 2, 9 and 10
+    assert_eq!(map.count_trees(slope(0, 1)), 3);
+    assert_eq!(map.count_trees(slope(0, 20)), 0);
+    assert_eq!(map.count_trees(slope(11, 1)), 3);
+}
+
+#[test]
+fn huge_slopes_wrap_without_overflowing() {
+    let challenge = Day03::new(SAMPLE_DATA).unwrap();
+    let map = challenge.map();
+
+    assert_eq!(map.count_trees(slope(usize::MAX, 1)), 2);
+    assert_eq!(map.count_trees(slope(3 + 11 * 1000, 1)), 7);
+}
+
+#[test]
+fn slopes_must_move_down() {
+    assert_eq!(
+        Slope::new(3, 0),
+        Err("Slope right 3, down 0 never reaches the bottom of the map.".into())
+    );
+}
+
+fn sort_key(ranking: &SlopeTrees) -> (usize, usize, usize) {
+    (ranking.trees, ranking.slope.down(), ranking.slope.right())
+}
+
+#[test]
+fn slopes_are_ranked_by_fewest_trees() {
+    let map = TreeMap::parse(SAMPLE_DATA).unwrap();
+    let ranked = map.rank_slopes(7, 2);
+
+    assert_eq!(ranked.len(), 16);
+    assert!(ranked
+        .windows(2)
+        .all(|pair| sort_key(&pair[0]) < sort_key(&pair[1])));
+    for ranking in &ranked {
+        assert_eq!(ranking.trees, map.count_trees(ranking.slope));
+    }
+    assert_eq!(map.best_slope(7, 2), Ok(ranked[0]));
+}
+
+#[test]
+fn best_slope_needs_a_slope_to_search() {
+    let map = TreeMap::parse(SAMPLE_DATA).unwrap();
+
+    assert!(map.best_slope(3, 0).is_err());
+}
+
+#[test]
+fn invalid_maps_are_errors() {
+    assert_eq!(
+        Day03::new("..#\n.x."),
+        Err("Invalid map square on line 2 column 2: 'x'".into())
+    );
+    assert_eq!(
+        Day03::new("..#\n...."),
+        Err("Line 2 of the map is 4 squares wide, expected 3.".into())
+    );
+    assert_eq!(Day03::new(""), Err("Map has no squares.".into()));
+}
+
+#[test]
+fn report_lists_gold_slopes_and_best_slope() {
+    let challenge = Day03::new(SAMPLE_DATA).unwrap();
+
+    assert_eq!(
+        challenge.report(),
+        vec![
+            "right 1, down 1: 2 trees",
+            "right 3, down 1: 7 trees",
+            "right 5, down 1: 3 trees",
+            "right 7, down 1: 4 trees",
+            "right 1, down 2: 2 trees",
+            "Fewest trees up to right 7, down 2: right 5, down 2 with 0 trees",
+        ]
+    );
+}
+
+#[test]
+fn map_serializes_back_into_input() {
+    let challenge = Day03::new(SAMPLE_DATA).unwrap();
+
+    assert_eq!(challenge.to_string(), SAMPLE_DATA);
+}
+
+#[test]
+fn generated_data_builds_ok() {
+    let data = Day03::generate_input(1_000, 2020);
+
+    // unwrap will fail the test here if the day input parsing returned an Err
+    Day03::new(&data).unwrap();
+}
+
+#[test]
+fn generated_data_is_reproducible_from_seed() {
+    assert_eq!(Day03::generate_input(100, 7), Day03::generate_input(100, 7));
+    assert_ne!(Day03::generate_input(100, 7), Day03::generate_input(100, 8));
 }
 
 #[test]
-fn sample_data_for_gold_solution_has_ok_result() {
-    let mut challenge = Day03::new(SAMPLE_DATA).unwrap();
+fn generated_data_has_requested_number_of_rows() {
+    let data = Day03::generate_input(250, 2020);
 
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_gold().unwrap();
+    assert_eq!(data.lines().count(), 250);
+    assert!(data.lines().all(|line| line.len() == 31));
+    assert!(data.starts_with('.'));
 }
 
 #[test]
-fn sample_data_for_gold_solution_has_ok_result_of() {
-    let mut challenge = Day03::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_gold();
+fn map_is_inspected() {
+    let challenge = Day03::new(SAMPLE_DATA).unwrap();
 
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
+    assert_eq!(
+        challenge.inspect(),
+        vec![
+            ("Map", "11 wide by 11 high".into()),
+            ("Trees", "37 of 121 squares".into()),
+        ]
+    );
 }
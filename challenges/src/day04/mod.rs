@@ -1,39 +1,142 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+mod passport;
+mod schema;
+
+use crate::{ChallengeInput, GoldChallenge, Inspect, Report, SilverChallenge};
+use std::collections::BTreeSet;
+
+pub use passport::{format_passports, parse_passports, Location, Passport, PassportField};
+pub use schema::{FieldSpec, Rule, Schema, Unit, Violation, GOLD_SCHEMA, SILVER_SCHEMA};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day04 {
-    data: String,
+    passports: Vec<Passport>,
 }
 
 impl Day04 {
     pub fn new(data: &str) -> Result<Self, String> {
-        Ok(Self { data: data.into() })
+        Ok(Self {
+            passports: parse_passports(data)?,
+        })
+    }
+
+    pub fn passports(&self) -> &[Passport] {
+        &self.passports
+    }
+
+    // every rule each passport breaks, in the order of the passports
+    pub fn validate(&self, schema: &Schema) -> Vec<Vec<Violation>> {
+        self.passports
+            .iter()
+            .map(|passport| schema.validate(passport))
+            .collect()
+    }
+
+    pub fn count_valid(&self, schema: &Schema) -> usize {
+        self.passports
+            .iter()
+            .filter(|passport| schema.is_valid(passport))
+            .count()
+    }
+}
+
+impl ChallengeInput for Day04 {}
+
+impl std::fmt::Display for Day04 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format_passports(&self.passports))
+    }
+}
+
+// keys outside the gold schema and keys given twice are what schemas trip over
+impl Inspect for Day04 {
+    fn inspect(&self) -> Vec<(&'static str, String)> {
+        let fields = self.passports.iter().flat_map(Passport::fields);
+        let keys = fields
+            .clone()
+            .map(|field| field.key.as_str())
+            .collect::<BTreeSet<_>>();
+        let repeated = self
+            .passports
+            .iter()
+            .filter(|passport| {
+                let keys = passport.fields().iter().map(|field| &field.key);
+                keys.collect::<BTreeSet<_>>().len() < passport.fields().len()
+            })
+            .count();
+
+        vec![
+            ("Passports", self.passports.len().to_string()),
+            ("Fields", fields.count().to_string()),
+            ("Keys", keys.into_iter().collect::<Vec<_>>().join(" ")),
+            ("Passports with repeated keys", repeated.to_string()),
+        ]
     }
 }
 
 impl SilverChallenge for Day04 {
-    type Answer = ();
-    type Error = &'static str;
+    type Answer = usize;
+    type Error = String;
 
     fn attempt_silver(&mut self) -> Result<Self::Answer, Self::Error>
     where
         Self::Answer: std::fmt::Debug,
     {
-        Err("NYI")
+        Ok(self.count_valid(&SILVER_SCHEMA))
     }
 }
 
 impl GoldChallenge for Day04 {
-    type Answer = ();
-    type Error = &'static str;
+    type Answer = usize;
+    type Error = String;
 
     fn attempt_gold(&mut self) -> Result<Self::Answer, Self::Error>
     where
         Self::Answer: std::fmt::Debug,
     {
-        Err("NYI")
+        Ok(self.count_valid(&GOLD_SCHEMA))
+    }
+}
+
+// a passport failing silver is missing a field, so also fails gold, whose
+// violations are the ones listed
+impl Report for Day04 {
+    fn report(&self) -> Vec<String> {
+        let failures = self
+            .passports
+            .iter()
+            .filter_map(|passport| {
+                let violations = GOLD_SCHEMA.validate(passport);
+                if violations.is_empty() {
+                    return None;
+                }
+
+                let parts = match SILVER_SCHEMA.is_valid(passport) {
+                    true => "gold",
+                    false => "silver and gold",
+                };
+                let lines = match passport.lines() {
+                    (first, last) if first == last => format!("line {}", first),
+                    (first, last) => format!("lines {}-{}", first, last),
+                };
+                let violations = violations
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>();
+                Some(format!(
+                    "Passport on {} fails {}: {}",
+                    lines,
+                    parts,
+                    violations.join("; ")
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        match failures.is_empty() {
+            true => vec!["Every passport passes both schemas".into()],
+            false => failures,
+        }
     }
 }
@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod tests;
+
+// Where a field starts in the batch file, counting lines and columns from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PassportField {
+    pub key: String,
+    pub value: String,
+    pub location: Location,
+}
+
+// The `key:value` fields of a passport in the order they were given, keeping any
+// repeated or unknown keys so that a schema can decide what to make of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Passport {
+    fields: Vec<PassportField>,
+}
+
+impl Passport {
+    pub fn fields(&self) -> &[PassportField] {
+        &self.fields
+    }
+
+    // the fields with the key, usually at most one
+    pub fn fields_with_key<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> impl Iterator<Item = &'a PassportField> + 'a {
+        self.fields.iter().filter(move |field| field.key == key)
+    }
+
+    pub fn values<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.fields_with_key(key).map(|field| field.value.as_str())
+    }
+
+    // the first and last lines the passport's fields are on
+    pub fn lines(&self) -> (usize, usize) {
+        let line = |field: Option<&PassportField>| field.map_or(0, |field| field.location.line);
+        (line(self.fields.first()), line(self.fields.last()))
+    }
+}
+
+// Parses a batch of passports, which are separated by lines holding nothing but
+// whitespace. Fields are separated by any whitespace, and lines may end in CRLF.
+pub fn parse_passports(data: &str) -> Result<Vec<Passport>, String> {
+    let mut passports = Vec::new();
+    let mut fields = Vec::new();
+
+    for (index, line) in data.split('\n').enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line.trim().is_empty() {
+            if !fields.is_empty() {
+                passports.push(Passport {
+                    fields: std::mem::take(&mut fields),
+                });
+            }
+            continue;
+        }
+
+        for (column, token) in tokens(line) {
+            let location = Location {
+                line: index + 1,
+                column,
+            };
+            let (key, value) = token
+                .split_once(':')
+                .filter(|(key, _)| !key.is_empty())
+                .ok_or_else(|| {
+                    format!(
+                        "Invalid passport field at {}: expected key:value, found {:?}",
+                        location, token
+                    )
+                })?;
+            fields.push(PassportField {
+                key: key.into(),
+                value: value.into(),
+                location,
+            });
+        }
+    }
+
+    if !fields.is_empty() {
+        passports.push(Passport { fields });
+    }
+    Ok(passports)
+}
+
+// the runs of non-whitespace characters in a line, with the column each starts at
+fn tokens(line: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+
+    for (column, (index, character)) in line.char_indices().enumerate() {
+        match (character.is_whitespace(), start) {
+            (false, None) => start = Some((column + 1, index)),
+            (true, Some((start_column, start_index))) => {
+                tokens.push((start_column, &line[start_index..index]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some((start_column, start_index)) = start {
+        tokens.push((start_column, &line[start_index..]));
+    }
+
+    tokens
+}
+
+// Writes each field back at the line and column it was read from, padding with
+// spaces and blank lines, so the batch parses back into the same passports.
+pub fn format_passports(passports: &[Passport]) -> String {
+    let mut output = String::new();
+    let mut current = Location { line: 1, column: 1 };
+
+    for field in passports.iter().flat_map(|passport| &passport.fields) {
+        while current.line < field.location.line {
+            output.push('\n');
+            current = Location {
+                line: current.line + 1,
+                column: 1,
+            };
+        }
+        // a field further left than the end of the previous one still needs a separator
+        let padding = match field.location.column.checked_sub(current.column) {
+            Some(padding) if padding > 0 || current.column == 1 => padding,
+            _ => 1,
+        };
+        output.push_str(&" ".repeat(padding));
+
+        let text = format!("{}:{}", field.key, field.value);
+        current.column += padding + text.chars().count();
+        output.push_str(&text);
+    }
+
+    output
+}
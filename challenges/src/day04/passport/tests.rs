@@ -0,0 +1,103 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+fn keys_and_locations(passport: &Passport) -> Vec<(&str, usize, usize)> {
+    passport
+        .fields()
+        .iter()
+        .map(|field| {
+            (
+                field.key.as_str(),
+                field.location.line,
+                field.location.column,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn fields_are_located_by_line_and_column() {
+    let passports = parse_passports("ecl:gry pid:860033327\nbyr:1937\n\nhcl:#cfa07d").unwrap();
+
+    assert_eq!(passports.len(), 2);
+    assert_eq!(
+        keys_and_locations(&passports[0]),
+        vec![("ecl", 1, 1), ("pid", 1, 9), ("byr", 2, 1)]
+    );
+    assert_eq!(keys_and_locations(&passports[1]), vec![("hcl", 4, 1)]);
+    assert_eq!(passports[0].lines(), (1, 2));
+    assert_eq!(passports[1].lines(), (4, 4));
+}
+
+#[test]
+fn any_whitespace_layout_is_accepted() {
+    let data = "\r\n  ecl:gry\t\tpid:860033327  \r\n byr:1937\r\n \t \r\n\r\n\r\nhcl:#cfa07d\r\n";
+    let passports = parse_passports(data).unwrap();
+
+    assert_eq!(passports.len(), 2);
+    assert_eq!(
+        keys_and_locations(&passports[0]),
+        vec![("ecl", 2, 3), ("pid", 2, 12), ("byr", 3, 2)]
+    );
+    assert_eq!(
+        passports[0].values("pid").collect::<Vec<_>>(),
+        vec!["860033327"]
+    );
+    assert_eq!(keys_and_locations(&passports[1]), vec![("hcl", 7, 1)]);
+}
+
+#[test]
+fn repeated_and_unknown_keys_are_kept() {
+    let passports = parse_passports("byr:1937 xyz:1 byr:1938 url:http://a").unwrap();
+
+    assert_eq!(
+        passports[0].values("byr").collect::<Vec<_>>(),
+        vec!["1937", "1938"]
+    );
+    assert_eq!(passports[0].values("xyz").collect::<Vec<_>>(), vec!["1"]);
+    assert_eq!(
+        passports[0].values("url").collect::<Vec<_>>(),
+        vec!["http://a"]
+    );
+}
+
+#[test]
+fn values_may_be_empty_but_keys_may_not() {
+    let passports = parse_passports("cid:").unwrap();
+    assert_eq!(passports[0].values("cid").collect::<Vec<_>>(), vec![""]);
+
+    assert_eq!(
+        parse_passports("byr:1937\n  :1937"),
+        Err(
+            "Invalid passport field at line 2, column 3: expected key:value, found \":1937\""
+                .into()
+        )
+    );
+}
+
+#[test]
+fn empty_batch_has_no_passports() {
+    assert_eq!(parse_passports(""), Ok(vec![]));
+    assert_eq!(parse_passports("\n \n"), Ok(vec![]));
+}
+
+#[test]
+fn formatting_keeps_the_layout() {
+    let data = "\n  ecl:gry\tpid:860033327\n byr:1937\n\n\n\nhcl:#cfa07d";
+    let passports = parse_passports(data).unwrap();
+    let formatted = format_passports(&passports);
+
+    assert_eq!(
+        formatted,
+        "\n  ecl:gry pid:860033327\n byr:1937\n\n\n\nhcl:#cfa07d"
+    );
+    assert_eq!(parse_passports(&formatted), Ok(passports));
+}
+
+#[test]
+fn location_is_described() {
+    assert_eq!(
+        Location { line: 3, column: 7 }.to_string(),
+        "line 3, column 7"
+    );
+}
@@ -0,0 +1,287 @@
+#[cfg(test)]
+mod tests;
+
+use super::{Location, Passport};
+
+// The fields a passport is checked for, and the rule each field's value follows.
+// Fields of a passport which are not in the schema are ignored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Schema {
+    pub fields: &'static [FieldSpec],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldSpec {
+    pub key: &'static str,
+    pub name: &'static str,
+    pub required: bool,
+    pub rule: Rule,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rule {
+    Any,
+    // a whole number within a range, optionally written with an exact number of digits
+    Number {
+        digits: Option<usize>,
+        min: u64,
+        max: u64,
+    },
+    // a whole number followed by one of the units, within that unit's range
+    Measurement(&'static [Unit]),
+    // a prefix followed by exactly `length` of the allowed characters
+    Characters {
+        prefix: &'static str,
+        length: usize,
+        allowed: &'static str,
+    },
+    OneOf(&'static [&'static str]),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Unit {
+    pub suffix: &'static str,
+    pub min: u64,
+    pub max: u64,
+}
+
+// silver only checks that every field but `cid` is present
+pub const SILVER_SCHEMA: Schema = Schema {
+    fields: &[
+        FieldSpec::required("byr", "Birth Year", Rule::Any),
+        FieldSpec::required("iyr", "Issue Year", Rule::Any),
+        FieldSpec::required("eyr", "Expiration Year", Rule::Any),
+        FieldSpec::required("hgt", "Height", Rule::Any),
+        FieldSpec::required("hcl", "Hair Color", Rule::Any),
+        FieldSpec::required("ecl", "Eye Color", Rule::Any),
+        FieldSpec::required("pid", "Passport ID", Rule::Any),
+        FieldSpec::optional("cid", "Country ID", Rule::Any),
+    ],
+};
+
+// gold also checks the value of every field but `cid`
+pub const GOLD_SCHEMA: Schema = Schema {
+    fields: &[
+        FieldSpec::required("byr", "Birth Year", Rule::year(1920, 2002)),
+        FieldSpec::required("iyr", "Issue Year", Rule::year(2010, 2020)),
+        FieldSpec::required("eyr", "Expiration Year", Rule::year(2020, 2030)),
+        FieldSpec::required(
+            "hgt",
+            "Height",
+            Rule::Measurement(&[
+                Unit {
+                    suffix: "cm",
+                    min: 150,
+                    max: 193,
+                },
+                Unit {
+                    suffix: "in",
+                    min: 59,
+                    max: 76,
+                },
+            ]),
+        ),
+        FieldSpec::required(
+            "hcl",
+            "Hair Color",
+            Rule::Characters {
+                prefix: "#",
+                length: 6,
+                allowed: "0123456789abcdef",
+            },
+        ),
+        FieldSpec::required(
+            "ecl",
+            "Eye Color",
+            Rule::OneOf(&["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]),
+        ),
+        FieldSpec::required(
+            "pid",
+            "Passport ID",
+            Rule::Characters {
+                prefix: "",
+                length: 9,
+                allowed: "0123456789",
+            },
+        ),
+        FieldSpec::optional("cid", "Country ID", Rule::Any),
+    ],
+};
+
+impl FieldSpec {
+    pub const fn required(key: &'static str, name: &'static str, rule: Rule) -> Self {
+        Self {
+            key,
+            name,
+            required: true,
+            rule,
+        }
+    }
+
+    pub const fn optional(key: &'static str, name: &'static str, rule: Rule) -> Self {
+        Self {
+            key,
+            name,
+            required: false,
+            rule,
+        }
+    }
+}
+
+impl Rule {
+    // four digits within a range of years
+    pub const fn year(min: u64, max: u64) -> Self {
+        Rule::Number {
+            digits: Some(4),
+            min,
+            max,
+        }
+    }
+
+    // explains why a value does not follow the rule
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        match self {
+            Rule::Any => Ok(()),
+            Rule::Number { digits, min, max } => {
+                if let Some(digits) = digits {
+                    if value.chars().count() != *digits {
+                        return Err(format!("expected {} digits", digits));
+                    }
+                }
+                check_number(value, *min, *max, "")
+            }
+            Rule::Measurement(units) => {
+                let unit = units
+                    .iter()
+                    .find(|unit| value.ends_with(unit.suffix))
+                    .ok_or_else(|| {
+                        let suffixes = units.iter().map(|unit| unit.suffix).collect::<Vec<_>>();
+                        format!("expected a unit of {}", suffixes.join(" or "))
+                    })?;
+                let number = &value[..value.len() - unit.suffix.len()];
+                check_number(number, unit.min, unit.max, unit.suffix)
+            }
+            Rule::Characters {
+                prefix,
+                length,
+                allowed,
+            } => match value.strip_prefix(prefix) {
+                Some(rest) => check_characters(rest, *length, allowed),
+                None => Err(format!("expected to start with {}", prefix)),
+            },
+            Rule::OneOf(options) => match options.contains(&value) {
+                true => Ok(()),
+                false => Err(format!("expected one of {}", options.join(", "))),
+            },
+        }
+    }
+}
+
+fn check_characters(value: &str, length: usize, allowed: &str) -> Result<(), String> {
+    if let Some(character) = value.chars().find(|c| !allowed.contains(*c)) {
+        return Err(format!("unexpected character {:?}", character));
+    }
+    match value.chars().count() {
+        count if count == length => Ok(()),
+        count => Err(format!("expected {} characters, found {}", length, count)),
+    }
+}
+
+// only plain digits, as parsing alone would also take a leading `+`
+fn check_number(value: &str, min: u64, max: u64, unit: &str) -> Result<(), String> {
+    let number = Some(value)
+        .filter(|value| !value.is_empty() && value.chars().all(|c| c.is_ascii_digit()))
+        .and_then(|value| value.parse::<u64>().ok())
+        .ok_or_else(|| format!("{:?} is not a number", value))?;
+    match (min..=max).contains(&number) {
+        true => Ok(()),
+        false => Err(format!(
+            "{}{} not in {}{}..={}{}",
+            number, unit, min, unit, max, unit
+        )),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    MissingField {
+        key: &'static str,
+        name: &'static str,
+    },
+    DuplicateField {
+        key: &'static str,
+        locations: Vec<Location>,
+    },
+    InvalidValue {
+        key: &'static str,
+        value: String,
+        reason: String,
+        location: Location,
+    },
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::MissingField { key, name } => write!(f, "missing {} ({})", key, name),
+            Violation::DuplicateField { key, locations } => {
+                let locations = locations
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>();
+                write!(
+                    f,
+                    "{} given {} times, at {}",
+                    key,
+                    locations.len(),
+                    locations.join(" and ")
+                )
+            }
+            Violation::InvalidValue {
+                key,
+                value,
+                reason,
+                location,
+            } => write!(f, "{}: invalid {}:{}, {}", location, key, value, reason),
+        }
+    }
+}
+
+impl Schema {
+    // every rule of the schema the passport breaks, in the order of the schema's fields
+    pub fn validate(&self, passport: &Passport) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        for spec in self.fields {
+            let fields = passport.fields_with_key(spec.key).collect::<Vec<_>>();
+            match fields.len() {
+                0 if spec.required => violations.push(Violation::MissingField {
+                    key: spec.key,
+                    name: spec.name,
+                }),
+                0 | 1 => {}
+                _ => violations.push(Violation::DuplicateField {
+                    key: spec.key,
+                    locations: fields.iter().map(|field| field.location).collect(),
+                }),
+            }
+
+            for field in fields {
+                if let Err(reason) = spec.rule.check(&field.value) {
+                    violations.push(Violation::InvalidValue {
+                        key: spec.key,
+                        value: field.value.clone(),
+                        reason,
+                        location: field.location,
+                    });
+                }
+            }
+        }
+
+        violations
+    }
+
+    pub fn is_valid(&self, passport: &Passport) -> bool {
+        self.validate(passport).is_empty()
+    }
+}
@@ -0,0 +1,152 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+use crate::day04::parse_passports;
+
+fn gold_rule(key: &str) -> Rule {
+    GOLD_SCHEMA
+        .fields
+        .iter()
+        .find(|spec| spec.key == key)
+        .unwrap()
+        .rule
+}
+
+fn passport(fields: &str) -> Passport {
+    parse_passports(fields).unwrap().remove(0)
+}
+
+#[test]
+fn gold_rules_accept_puzzle_examples() {
+    for (key, value) in [
+        ("byr", "2002"),
+        ("hgt", "60in"),
+        ("hgt", "190cm"),
+        ("hcl", "#123abc"),
+        ("ecl", "brn"),
+        ("pid", "000000001"),
+    ]
+    .iter()
+    {
+        assert_eq!(gold_rule(key).check(value), Ok(()), "{}:{}", key, value);
+    }
+}
+
+#[test]
+fn gold_rules_explain_puzzle_examples() {
+    let check = |key, value| gold_rule(key).check(value).unwrap_err();
+
+    assert_eq!(check("byr", "2003"), "2003 not in 1920..=2002");
+    assert_eq!(check("byr", "02002"), "expected 4 digits");
+    assert_eq!(check("hgt", "190in"), "190in not in 59in..=76in");
+    assert_eq!(check("hgt", "190"), "expected a unit of cm or in");
+    assert_eq!(check("hgt", "cm"), "\"\" is not a number");
+    assert_eq!(check("hgt", "+170cm"), "\"+170\" is not a number");
+    assert_eq!(check("byr", "+980"), "\"+980\" is not a number");
+    assert_eq!(check("hcl", "#123abz"), "unexpected character 'z'");
+    assert_eq!(check("hcl", "123abc"), "expected to start with #");
+    assert_eq!(check("hcl", "#123ab"), "expected 6 characters, found 5");
+    assert_eq!(
+        check("ecl", "wat"),
+        "expected one of amb, blu, brn, gry, grn, hzl, oth"
+    );
+    assert_eq!(
+        check("pid", "0123456789"),
+        "expected 9 characters, found 10"
+    );
+}
+
+#[test]
+fn signed_numbers_are_invalid() {
+    let passport =
+        passport("ecl:gry pid:860033327 eyr:2020 hcl:#fffffd byr:1937 iyr:2017 hgt:+170cm");
+
+    assert!(SILVER_SCHEMA.is_valid(&passport));
+    assert!(!GOLD_SCHEMA.is_valid(&passport));
+}
+
+#[test]
+fn silver_schema_only_checks_presence() {
+    let complete = passport("byr:x iyr:x eyr:x hgt:x hcl:x ecl:x pid:x");
+
+    assert!(SILVER_SCHEMA.is_valid(&complete));
+    assert!(!GOLD_SCHEMA.is_valid(&complete));
+    assert_eq!(
+        SILVER_SCHEMA.validate(&passport("iyr:x eyr:x hgt:x hcl:x ecl:x pid:x cid:1")),
+        vec![Violation::MissingField {
+            key: "byr",
+            name: "Birth Year"
+        }]
+    );
+}
+
+#[test]
+fn every_violation_is_returned() {
+    let violations = GOLD_SCHEMA.validate(&passport(
+        "eyr:1972 cid:100 hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018",
+    ));
+
+    assert_eq!(
+        violations
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec![
+            "missing byr (Birth Year)",
+            "line 1, column 1: invalid eyr:1972, 1972 not in 2020..=2030",
+            "line 1, column 38: invalid hgt:170, expected a unit of cm or in",
+            "line 1, column 46: invalid pid:186cm, unexpected character 'c'",
+        ]
+    );
+}
+
+#[test]
+fn repeated_fields_are_violations() {
+    let violations = SILVER_SCHEMA.validate(&passport(
+        "byr:1 byr:2 iyr:x eyr:x hgt:x hcl:x ecl:x pid:x cid:1 cid:2",
+    ));
+
+    assert_eq!(
+        violations,
+        vec![
+            Violation::DuplicateField {
+                key: "byr",
+                locations: vec![
+                    Location { line: 1, column: 1 },
+                    Location { line: 1, column: 7 }
+                ]
+            },
+            Violation::DuplicateField {
+                key: "cid",
+                locations: vec![
+                    Location {
+                        line: 1,
+                        column: 49
+                    },
+                    Location {
+                        line: 1,
+                        column: 55
+                    }
+                ]
+            },
+        ]
+    );
+}
+
+#[test]
+fn custom_schemas_are_data() {
+    const SCHEMA: Schema = Schema {
+        fields: &[FieldSpec::required(
+            "cid",
+            "Country ID",
+            Rule::Number {
+                digits: None,
+                min: 1,
+                max: 500,
+            },
+        )],
+    };
+
+    assert!(SCHEMA.is_valid(&passport("cid:7 unknown:field")));
+    assert!(!SCHEMA.is_valid(&passport("cid:501")));
+    assert!(!SCHEMA.is_valid(&passport("byr:1937")));
+}
@@ -1,44 +1,148 @@
 // import everything from the parent module file (in this case mod.rs)
 use super::*;
 
-const SAMPLE_DATA: &'static str = "<replace this with sample data from challenge>";
+const SAMPLE_DATA: &str = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in";
+
+const INVALID_PASSPORTS: &str = "eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007";
+
+const VALID_PASSPORTS: &str = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719";
+
+sample_tests!(Day04, [
+    { data: SAMPLE_DATA, silver: Ok(2) },
+    { data: INVALID_PASSPORTS, gold: Ok(0) },
+    { data: VALID_PASSPORTS, gold: Ok(4) },
+], input: { file: "day04.txt", silver: Ok(170), gold: Ok(103) });
+
+round_trip_property!(Day04, crate::properties::passport_batch());
+
+#[test]
+fn passports_keep_fields_in_order() {
+    let challenge = Day04::new("hcl:#ae17e1 iyr:2013\neyr:2024\n\nbyr:1931").unwrap();
+
+    assert_eq!(challenge.passports().len(), 2);
+    assert_eq!(
+        challenge.passports()[0]
+            .fields()
+            .iter()
+            .map(|field| (field.key.as_str(), field.value.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("hcl", "#ae17e1"), ("iyr", "2013"), ("eyr", "2024")]
+    );
+    assert_eq!(
+        challenge.passports()[1].values("byr").collect::<Vec<_>>(),
+        vec!["1931"]
+    );
+}
+
+#[test]
+fn violations_are_listed_per_passport() {
+    let challenge = Day04::new(SAMPLE_DATA).unwrap();
+    let violations = challenge.validate(&SILVER_SCHEMA);
+
+    assert_eq!(violations.len(), 4);
+    assert!(violations[0].is_empty());
+    assert_eq!(
+        violations[1],
+        vec![Violation::MissingField {
+            key: "hgt",
+            name: "Height"
+        }]
+    );
+    assert!(violations[2].is_empty());
+    assert_eq!(violations[3].len(), 1);
+}
 
 #[test]
-fn sample_data_builds_ok() {
-    // unwrap will fail the test here if the day input parsing returned an Err
-    Day04::new(SAMPLE_DATA).unwrap();
+fn invalid_field_is_reported_with_passport() {
+    assert_eq!(
+        Day04::new("byr:1937\n\nbyr:1937 iyr2017"),
+        Err(
+            "Invalid passport field at line 3, column 10: expected key:value, found \"iyr2017\""
+                .into()
+        )
+    );
 }
 
 #[test]
-fn sample_data_for_silver_solution_has_ok_result() {
-    let mut challenge = Day04::new(SAMPLE_DATA).unwrap();
+fn report_locates_failing_passports() {
+    let challenge = Day04::new(SAMPLE_DATA).unwrap();
 
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_silver().unwrap();
+    assert_eq!(
+        challenge.report(),
+        vec![
+            "Passport on lines 4-5 fails silver and gold: missing hgt (Height)",
+            "Passport on lines 12-13 fails silver and gold: missing byr (Birth Year)",
+        ]
+    );
 }
 
 #[test]
-fn sample_data_for_silver_solution_has_ok_result_of() {
-    let mut challenge = Day04::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_silver();
+fn report_of_invalid_values() {
+    let challenge = Day04::new(INVALID_PASSPORTS).unwrap();
 
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
+    assert_eq!(
+        challenge.report()[2],
+        "Passport on lines 8-9 fails gold: line 8, column 1: invalid hcl:dab227, \
+         expected to start with #"
+    );
 }
 
 #[test]
-fn sample_data_for_gold_solution_has_ok_result() {
-    let mut challenge = Day04::new(SAMPLE_DATA).unwrap();
+fn report_of_all_valid_passports() {
+    let challenge = Day04::new(VALID_PASSPORTS).unwrap();
 
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_gold().unwrap();
+    assert_eq!(
+        challenge.report(),
+        vec!["Every passport passes both schemas"]
+    );
 }
 
 #[test]
-fn sample_data_for_gold_solution_has_ok_result_of() {
-    let mut challenge = Day04::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_gold();
+fn passports_are_inspected() {
+    let challenge = Day04::new(&format!("{}\n\nhgt:59in hgt:60in", SAMPLE_DATA)).unwrap();
 
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
+    assert_eq!(
+        challenge.inspect(),
+        vec![
+            ("Passports", "5".into()),
+            ("Fields", "30".into()),
+            ("Keys", "byr cid ecl eyr hcl hgt iyr pid".into()),
+            ("Passports with repeated keys", "1".into()),
+        ]
+    );
 }
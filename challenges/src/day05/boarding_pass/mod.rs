@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod tests;
+
+// How many rows and columns of seats the plane has, as the number of bits of a
+// boarding pass code picking each. Seat IDs are the row and column bits together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PlaneGeometry {
+    row_bits: u32,
+    column_bits: u32,
+}
+
+// 128 rows of 8 seats
+pub const DEFAULT_GEOMETRY: PlaneGeometry = PlaneGeometry {
+    row_bits: 7,
+    column_bits: 3,
+};
+
+impl PlaneGeometry {
+    pub fn new(row_bits: u32, column_bits: u32) -> Result<Self, String> {
+        match row_bits.checked_add(column_bits) {
+            Some(0) => Err("Plane must have at least one row or column bit.".into()),
+            Some(bits) if bits <= 32 => Ok(Self {
+                row_bits,
+                column_bits,
+            }),
+            _ => Err(format!(
+                "Plane of {} bits is too large, at most 32 are supported.",
+                u64::from(row_bits) + u64::from(column_bits)
+            )),
+        }
+    }
+
+    pub fn row_bits(&self) -> u32 {
+        self.row_bits
+    }
+
+    pub fn column_bits(&self) -> u32 {
+        self.column_bits
+    }
+
+    pub fn rows(&self) -> u64 {
+        1 << self.row_bits
+    }
+
+    pub fn columns(&self) -> u64 {
+        1 << self.column_bits
+    }
+
+    pub fn seats(&self) -> u64 {
+        self.rows() * self.columns()
+    }
+
+    pub fn code_length(&self) -> usize {
+        (self.row_bits + self.column_bits) as usize
+    }
+}
+
+impl Default for PlaneGeometry {
+    fn default() -> Self {
+        DEFAULT_GEOMETRY
+    }
+}
+
+// A seat on a plane, decoded from or encoded into a boarding pass code such as
+// `FBFBBFFRLR`, where each `F` or `B` keeps the front or back half of the rows
+// and each `L` or `R` the left or right half of the columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BoardingPass {
+    row: u32,
+    column: u32,
+    geometry: PlaneGeometry,
+}
+
+impl BoardingPass {
+    pub fn new(row: u32, column: u32, geometry: PlaneGeometry) -> Result<Self, String> {
+        if u64::from(row) >= geometry.rows() {
+            return Err(format!(
+                "Row {} is beyond the last row {}.",
+                row,
+                geometry.rows() - 1
+            ));
+        }
+        if u64::from(column) >= geometry.columns() {
+            return Err(format!(
+                "Column {} is beyond the last column {}.",
+                column,
+                geometry.columns() - 1
+            ));
+        }
+        Ok(Self {
+            row,
+            column,
+            geometry,
+        })
+    }
+
+    pub fn decode(code: &str, geometry: PlaneGeometry) -> Result<Self, String> {
+        let length = code.chars().count();
+        if length != geometry.code_length() {
+            return Err(format!(
+                "Boarding pass {:?} is {} characters long, expected {}.",
+                code,
+                length,
+                geometry.code_length()
+            ));
+        }
+
+        let mut row = 0;
+        let mut column = 0;
+        for (index, character) in code.chars().enumerate() {
+            let is_row = index < geometry.row_bits as usize;
+            let bit = match (is_row, character) {
+                (true, 'F') | (false, 'L') => 0,
+                (true, 'B') | (false, 'R') => 1,
+                (true, _) => return Err(unexpected(code, index, character, "F or B")),
+                (false, _) => return Err(unexpected(code, index, character, "L or R")),
+            };
+            match is_row {
+                true => row = row << 1 | bit,
+                false => column = column << 1 | bit,
+            }
+        }
+
+        Ok(Self {
+            row,
+            column,
+            geometry,
+        })
+    }
+
+    pub fn from_seat_id(seat_id: u32, geometry: PlaneGeometry) -> Result<Self, String> {
+        if u64::from(seat_id) >= geometry.seats() {
+            return Err(format!(
+                "Seat ID {} is beyond the last seat {}.",
+                seat_id,
+                geometry.seats() - 1
+            ));
+        }
+        Ok(Self::at_seat_id(seat_id, geometry))
+    }
+
+    // the seat of an ID already known to be on the plane
+    pub(super) fn at_seat_id(seat_id: u32, geometry: PlaneGeometry) -> Self {
+        // shifting by all 32 bits of a u32 overflows, so shift as u64
+        let seat_id = u64::from(seat_id);
+        Self {
+            row: (seat_id >> geometry.column_bits) as u32,
+            column: (seat_id & (geometry.columns() - 1)) as u32,
+            geometry,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let bits = |value: u32, count: u32, zero: char, one: char| {
+            (0..count).rev().map(move |bit| match value >> bit & 1 {
+                0 => zero,
+                _ => one,
+            })
+        };
+        bits(self.row, self.geometry.row_bits, 'F', 'B')
+            .chain(bits(self.column, self.geometry.column_bits, 'L', 'R'))
+            .collect()
+    }
+
+    pub fn row(&self) -> u32 {
+        self.row
+    }
+
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+
+    pub fn geometry(&self) -> PlaneGeometry {
+        self.geometry
+    }
+
+    // the row times the number of columns, plus the column
+    pub fn seat_id(&self) -> u32 {
+        ((u64::from(self.row) << self.geometry.column_bits) | u64::from(self.column)) as u32
+    }
+}
+
+fn unexpected(code: &str, index: usize, character: char, expected: &str) -> String {
+    format!(
+        "Boarding pass {:?} has {:?} at position {}, expected {}.",
+        code,
+        character,
+        index + 1,
+        expected
+    )
+}
+
+impl std::fmt::Display for BoardingPass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.encode())
+    }
+}
@@ -0,0 +1,112 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+#[test]
+fn puzzle_examples_are_decoded() {
+    for (code, row, column, seat_id) in [
+        ("FBFBBFFRLR", 44, 5, 357),
+        ("BFFFBBFRRR", 70, 7, 567),
+        ("FFFBBBFRRR", 14, 7, 119),
+        ("BBFFBBFRLL", 102, 4, 820),
+    ]
+    .iter()
+    {
+        let pass = BoardingPass::decode(code, DEFAULT_GEOMETRY).unwrap();
+        assert_eq!((pass.row(), pass.column()), (*row, *column), "{}", code);
+        assert_eq!(pass.seat_id(), *seat_id, "{}", code);
+    }
+}
+
+#[test]
+fn seat_ids_are_encoded() {
+    let pass = BoardingPass::from_seat_id(357, DEFAULT_GEOMETRY).unwrap();
+
+    assert_eq!((pass.row(), pass.column()), (44, 5));
+    assert_eq!(pass.encode(), "FBFBBFFRLR");
+    assert_eq!(
+        BoardingPass::new(102, 4, DEFAULT_GEOMETRY)
+            .unwrap()
+            .to_string(),
+        "BBFFBBFRLL"
+    );
+}
+
+#[test]
+fn every_seat_round_trips() {
+    let geometry = PlaneGeometry::new(4, 2).unwrap();
+
+    for seat_id in 0..64 {
+        let pass = BoardingPass::from_seat_id(seat_id, geometry).unwrap();
+        assert_eq!(BoardingPass::decode(&pass.encode(), geometry), Ok(pass));
+        assert_eq!(pass.seat_id(), seat_id);
+    }
+}
+
+#[test]
+fn other_geometries_are_supported() {
+    let geometry = PlaneGeometry::new(2, 4).unwrap();
+    let pass = BoardingPass::decode("BFRLLR", geometry).unwrap();
+
+    assert_eq!((geometry.rows(), geometry.columns()), (4, 16));
+    assert_eq!((pass.row(), pass.column()), (2, 9));
+    assert_eq!(pass.seat_id(), 2 * 16 + 9);
+
+    let widest = PlaneGeometry::new(0, 32).unwrap();
+    let pass = BoardingPass::from_seat_id(u32::MAX, widest).unwrap();
+    assert_eq!(pass.encode(), "R".repeat(32));
+    assert_eq!(pass.seat_id(), u32::MAX);
+}
+
+#[test]
+fn invalid_geometries_are_errors() {
+    assert_eq!(
+        PlaneGeometry::new(0, 0),
+        Err("Plane must have at least one row or column bit.".into())
+    );
+    assert_eq!(
+        PlaneGeometry::new(30, 3),
+        Err("Plane of 33 bits is too large, at most 32 are supported.".into())
+    );
+    assert_eq!(
+        PlaneGeometry::new(u32::MAX, 1),
+        Err("Plane of 4294967296 bits is too large, at most 32 are supported.".into())
+    );
+}
+
+#[test]
+fn malformed_codes_are_precise_errors() {
+    let decode = |code| BoardingPass::decode(code, DEFAULT_GEOMETRY).unwrap_err();
+
+    assert_eq!(
+        decode("FBFBBFFRL"),
+        "Boarding pass \"FBFBBFFRL\" is 9 characters long, expected 10."
+    );
+    assert_eq!(
+        decode("FBFBBFFRLX"),
+        "Boarding pass \"FBFBBFFRLX\" has 'X' at position 10, expected L or R."
+    );
+    assert_eq!(
+        decode("FBFBBFRRLR"),
+        "Boarding pass \"FBFBBFRRLR\" has 'R' at position 7, expected F or B."
+    );
+    assert_eq!(
+        decode("fbfbbffrlr"),
+        "Boarding pass \"fbfbbffrlr\" has 'f' at position 1, expected F or B."
+    );
+}
+
+#[test]
+fn seats_beyond_the_plane_are_errors() {
+    assert_eq!(
+        BoardingPass::from_seat_id(1024, DEFAULT_GEOMETRY),
+        Err("Seat ID 1024 is beyond the last seat 1023.".into())
+    );
+    assert_eq!(
+        BoardingPass::new(128, 0, DEFAULT_GEOMETRY),
+        Err("Row 128 is beyond the last row 127.".into())
+    );
+    assert_eq!(
+        BoardingPass::new(0, 8, DEFAULT_GEOMETRY),
+        Err("Column 8 is beyond the last column 7.".into())
+    );
+}
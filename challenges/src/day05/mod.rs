@@ -1,39 +1,153 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+mod boarding_pass;
+mod seat_map;
+
+use crate::{ChallengeInput, GoldChallenge, Inspect, Report, SilverChallenge};
+
+pub use boarding_pass::{BoardingPass, PlaneGeometry, DEFAULT_GEOMETRY};
+pub use seat_map::{Gap, GapPosition, SeatMap};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day05 {
-    data: String,
+    passes: Vec<BoardingPass>,
+    geometry: PlaneGeometry,
 }
 
 impl Day05 {
     pub fn new(data: &str) -> Result<Self, String> {
-        Ok(Self { data: data.into() })
+        Self::with_geometry(data, DEFAULT_GEOMETRY)
+    }
+
+    // reads boarding passes of a plane with another number of rows or columns
+    pub fn with_geometry(data: &str, geometry: PlaneGeometry) -> Result<Self, String> {
+        let passes = data
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                BoardingPass::decode(line.trim(), geometry).map_err(|error| {
+                    format!("Invalid boarding pass on line {}: {}", index + 1, error)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { passes, geometry })
+    }
+
+    pub fn passes(&self) -> &[BoardingPass] {
+        &self.passes
+    }
+
+    pub fn geometry(&self) -> PlaneGeometry {
+        self.geometry
+    }
+
+    pub fn seat_map(&self) -> SeatMap {
+        SeatMap::new(self.geometry, &self.passes)
+    }
+}
+
+impl ChallengeInput for Day05 {}
+
+impl std::fmt::Display for Day05 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let codes = self
+            .passes
+            .iter()
+            .map(BoardingPass::encode)
+            .collect::<Vec<_>>();
+        write!(f, "{}", codes.join("\n"))
     }
 }
 
 impl SilverChallenge for Day05 {
-    type Answer = ();
-    type Error = &'static str;
+    type Answer = u32;
+    type Error = String;
 
     fn attempt_silver(&mut self) -> Result<Self::Answer, Self::Error>
     where
         Self::Answer: std::fmt::Debug,
     {
-        Err("NYI")
+        self.passes
+            .iter()
+            .map(BoardingPass::seat_id)
+            .max()
+            .ok_or_else(|| "No boarding passes.".to_string())
     }
 }
 
 impl GoldChallenge for Day05 {
-    type Answer = ();
-    type Error = &'static str;
+    type Answer = u32;
+    type Error = String;
 
+    // the ID of the seat missing between two seats with boarding passes
     fn attempt_gold(&mut self) -> Result<Self::Answer, Self::Error>
     where
         Self::Answer: std::fmt::Debug,
     {
-        Err("NYI")
+        self.seat_map().missing_seat().map(|seat| seat.seat_id())
+    }
+}
+
+impl Inspect for Day05 {
+    fn inspect(&self) -> Vec<(&'static str, String)> {
+        let seat_map = self.seat_map();
+        let seat_ids = self.passes.iter().map(BoardingPass::seat_id);
+
+        vec![
+            ("Boarding passes", self.passes.len().to_string()),
+            (
+                "Plane",
+                format!(
+                    "{} rows of {} seats",
+                    self.geometry.rows(),
+                    self.geometry.columns()
+                ),
+            ),
+            (
+                "Seats taken",
+                format!("{} of {}", seat_map.taken_seats(), self.geometry.seats()),
+            ),
+            (
+                "Seat IDs",
+                match (seat_ids.clone().min(), seat_ids.max()) {
+                    (Some(lowest), Some(highest)) => format!("{} to {}", lowest, highest),
+                    _ => "none".into(),
+                },
+            ),
+        ]
+    }
+}
+
+// the seats missing at the front and back and between boarding passes, then the
+// seat found for gold
+impl Report for Day05 {
+    fn report(&self) -> Vec<String> {
+        let seat_map = self.seat_map();
+        let mut lines = seat_map
+            .gaps()
+            .iter()
+            .map(|gap| {
+                let position = match gap.position() {
+                    GapPosition::Front => "at the front",
+                    GapPosition::Interior => "between boarding passes",
+                    GapPosition::Back => "at the back",
+                };
+                format!("Missing {}: {}", position, gap)
+            })
+            .collect::<Vec<_>>();
+
+        match seat_map.missing_seat() {
+            Ok(seat) => lines.push(format!(
+                "Your seat: row {}, column {} (ID {})",
+                seat.row(),
+                seat.column(),
+                seat.seat_id()
+            )),
+            Err(error) => lines.push(error),
+        }
+
+        lines
     }
 }
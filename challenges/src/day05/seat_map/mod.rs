@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod tests;
+
+use super::{BoardingPass, PlaneGeometry};
+use std::collections::BTreeSet;
+
+// Where a run of seats without boarding passes is. The plane's front and back
+// rows are missing altogether, while holes between taken seats are interior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GapPosition {
+    Front,
+    Interior,
+    Back,
+}
+
+// consecutive seat IDs without a boarding pass, from `first` to `last` inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gap {
+    first: BoardingPass,
+    last: BoardingPass,
+    position: GapPosition,
+}
+
+impl Gap {
+    pub fn first(&self) -> BoardingPass {
+        self.first
+    }
+
+    pub fn last(&self) -> BoardingPass {
+        self.last
+    }
+
+    pub fn position(&self) -> GapPosition {
+        self.position
+    }
+
+    pub fn seats(&self) -> u64 {
+        u64::from(self.last.seat_id()) - u64::from(self.first.seat_id()) + 1
+    }
+}
+
+// e.g. "3 seats from row 0, column 0 (ID 0) to row 0, column 2 (ID 2)"
+impl std::fmt::Display for Gap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let seat = |pass: BoardingPass| {
+            format!(
+                "row {}, column {} (ID {})",
+                pass.row(),
+                pass.column(),
+                pass.seat_id()
+            )
+        };
+        match self.seats() {
+            1 => write!(f, "1 seat at {}", seat(self.first)),
+            seats => write!(
+                f,
+                "{} seats from {} to {}",
+                seats,
+                seat(self.first),
+                seat(self.last)
+            ),
+        }
+    }
+}
+
+// Which seats of a plane have a boarding pass, displayed as a grid of a line per
+// row with `#` for taken seats, `.` for interior holes and `-` for seats missing
+// from the front or back.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatMap {
+    geometry: PlaneGeometry,
+    taken: BTreeSet<u32>,
+}
+
+impl SeatMap {
+    pub fn new(geometry: PlaneGeometry, passes: &[BoardingPass]) -> Self {
+        Self {
+            geometry,
+            taken: passes.iter().map(BoardingPass::seat_id).collect(),
+        }
+    }
+
+    pub fn geometry(&self) -> PlaneGeometry {
+        self.geometry
+    }
+
+    pub fn taken_seats(&self) -> usize {
+        self.taken.len()
+    }
+
+    pub fn is_taken(&self, row: u32, column: u32) -> Option<bool> {
+        BoardingPass::new(row, column, self.geometry)
+            .ok()
+            .map(|seat| self.taken.contains(&seat.seat_id()))
+    }
+
+    // every run of seats without a boarding pass, front to back
+    pub fn gaps(&self) -> Vec<Gap> {
+        let gap = |first: u32, last: u32, position| Gap {
+            first: BoardingPass::at_seat_id(first, self.geometry),
+            last: BoardingPass::at_seat_id(last, self.geometry),
+            position,
+        };
+        let last_seat = (self.geometry.seats() - 1) as u32;
+
+        // with no seat taken the whole plane is missing, counted from the front
+        let (front, back) = match (self.taken.iter().next(), self.taken.iter().next_back()) {
+            (Some(front), Some(back)) => (*front, *back),
+            _ => return vec![gap(0, last_seat, GapPosition::Front)],
+        };
+
+        let mut gaps = Vec::new();
+        if front > 0 {
+            gaps.push(gap(0, front - 1, GapPosition::Front));
+        }
+        let taken = self.taken.iter().copied().collect::<Vec<_>>();
+        gaps.extend(
+            taken
+                .windows(2)
+                .filter(|pair| pair[1] > pair[0] + 1)
+                .map(|pair| gap(pair[0] + 1, pair[1] - 1, GapPosition::Interior)),
+        );
+        if back < last_seat {
+            gaps.push(gap(back + 1, last_seat, GapPosition::Back));
+        }
+        gaps
+    }
+
+    // the single missing seat whose neighbours both have boarding passes
+    pub fn missing_seat(&self) -> Result<BoardingPass, String> {
+        let seats = self
+            .gaps()
+            .iter()
+            .filter(|gap| gap.position == GapPosition::Interior && gap.seats() == 1)
+            .map(Gap::first)
+            .collect::<Vec<_>>();
+        match seats.as_slice() {
+            [] => Err("No seat is missing between two boarding passes.".into()),
+            [seat] => Ok(*seat),
+            _ => Err(format!(
+                "Found {} seats missing between two boarding passes, expected one.",
+                seats.len()
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for SeatMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let front = self.taken.iter().next().copied();
+        let back = self.taken.iter().next_back().copied();
+        let rows = (0..self.geometry.rows())
+            .map(|row| {
+                (0..self.geometry.columns())
+                    .map(|column| {
+                        let seat_id = (row * self.geometry.columns() + column) as u32;
+                        match (front, back) {
+                            _ if self.taken.contains(&seat_id) => '#',
+                            (Some(front), Some(back)) if front < seat_id && seat_id < back => '.',
+                            _ => '-',
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", rows.join("\n"))
+    }
+}
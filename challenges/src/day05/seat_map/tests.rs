@@ -0,0 +1,86 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+// a plane of 4 rows of 4 seats
+fn seat_map(seat_ids: &[u32]) -> SeatMap {
+    let geometry = PlaneGeometry::new(2, 2).unwrap();
+    let passes = seat_ids
+        .iter()
+        .map(|seat_id| BoardingPass::from_seat_id(*seat_id, geometry).unwrap())
+        .collect::<Vec<_>>();
+    SeatMap::new(geometry, &passes)
+}
+
+fn gap_ids(seat_map: &SeatMap) -> Vec<(u32, u32, GapPosition)> {
+    seat_map
+        .gaps()
+        .iter()
+        .map(|gap| (gap.first().seat_id(), gap.last().seat_id(), gap.position()))
+        .collect()
+}
+
+#[test]
+fn occupancy_is_rendered_as_grid() {
+    let seat_map = seat_map(&[3, 4, 5, 7, 9, 10]);
+
+    assert_eq!(seat_map.to_string(), "---#\n##.#\n.##-\n----");
+    assert_eq!(seat_map.taken_seats(), 6);
+    assert_eq!(seat_map.is_taken(1, 3), Some(true));
+    assert_eq!(seat_map.is_taken(1, 2), Some(false));
+    assert_eq!(seat_map.is_taken(4, 0), None);
+}
+
+#[test]
+fn gaps_are_listed_front_to_back() {
+    assert_eq!(
+        gap_ids(&seat_map(&[3, 4, 5, 7, 9, 10])),
+        vec![
+            (0, 2, GapPosition::Front),
+            (6, 6, GapPosition::Interior),
+            (8, 8, GapPosition::Interior),
+            (11, 15, GapPosition::Back),
+        ]
+    );
+    assert_eq!(
+        gap_ids(&seat_map(&[0, 1, 4, 15])),
+        vec![
+            (2, 3, GapPosition::Interior),
+            (5, 14, GapPosition::Interior)
+        ]
+    );
+    assert_eq!(gap_ids(&seat_map(&[])), vec![(0, 15, GapPosition::Front)]);
+}
+
+#[test]
+fn gaps_give_rows_and_columns() {
+    let gaps = seat_map(&[6, 9]).gaps();
+
+    assert_eq!(gaps[0].seats(), 6);
+    assert_eq!(
+        gaps[0].to_string(),
+        "6 seats from row 0, column 0 (ID 0) to row 1, column 1 (ID 5)"
+    );
+    assert_eq!(
+        gaps[1].to_string(),
+        "2 seats from row 1, column 3 (ID 7) to row 2, column 0 (ID 8)"
+    );
+}
+
+#[test]
+fn missing_seat_has_row_and_column() {
+    let seat = seat_map(&[4, 5, 7, 12]).missing_seat().unwrap();
+
+    assert_eq!((seat.row(), seat.column(), seat.seat_id()), (1, 2, 6));
+}
+
+#[test]
+fn missing_seat_must_be_single() {
+    assert_eq!(
+        seat_map(&[3, 4, 5, 7, 9, 10]).missing_seat(),
+        Err("Found 2 seats missing between two boarding passes, expected one.".into())
+    );
+    assert_eq!(
+        seat_map(&[1, 2, 3, 14]).missing_seat(),
+        Err("No seat is missing between two boarding passes.".into())
+    );
+}
@@ -1,44 +1,87 @@
 // import everything from the parent module file (in this case mod.rs)
 use super::*;
 
-const SAMPLE_DATA: &'static str = "<replace this with sample data from challenge>";
+const SAMPLE_DATA: &str = "FBFBBFFRLR
+BFFFBBFRRR
+FFFBBBFRRR
+BBFFBBFRLL";
+
+// the sample has no seat between two others, so gold has its own
+const GOLD_SAMPLE_DATA: &str = "FFFFFFFLLL
+FFFFFFFLLR
+FFFFFFFLRR
+FFFFFFFRLL";
+
+sample_tests!(Day05, [
+    { data: SAMPLE_DATA, silver: Ok(820) },
+    { data: GOLD_SAMPLE_DATA, silver: Ok(4), gold: Ok(2) },
+], input: { file: "day05.txt", silver: Ok(801), gold: Ok(597) });
+
+round_trip_property!(Day05, crate::properties::boarding_passes());
+
+#[test]
+fn passes_of_other_geometries_are_read() {
+    let geometry = PlaneGeometry::new(2, 2).unwrap();
+    let mut challenge = Day05::with_geometry("FFLL\nBBRR\nFFRL", geometry).unwrap();
+
+    assert_eq!(challenge.passes().len(), 3);
+    assert_eq!(challenge.attempt_silver(), Ok(15));
+}
 
 #[test]
-fn sample_data_builds_ok() {
-    // unwrap will fail the test here if the day input parsing returned an Err
-    Day05::new(SAMPLE_DATA).unwrap();
+fn invalid_pass_is_reported_with_line() {
+    assert_eq!(
+        Day05::new("FBFBBFFRLR\nFBFBBFFRL"),
+        Err("Invalid boarding pass on line 2: \
+             Boarding pass \"FBFBBFFRL\" is 9 characters long, expected 10."
+            .into())
+    );
 }
 
 #[test]
-fn sample_data_for_silver_solution_has_ok_result() {
+fn missing_seat_needs_neighbours() {
     let mut challenge = Day05::new(SAMPLE_DATA).unwrap();
 
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_silver().unwrap();
+    assert_eq!(
+        challenge.attempt_gold(),
+        Err("No seat is missing between two boarding passes.".into())
+    );
 }
 
 #[test]
-fn sample_data_for_silver_solution_has_ok_result_of() {
-    let mut challenge = Day05::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_silver();
+fn gold_seat_is_found_on_seat_map() {
+    let challenge = Day05::new(GOLD_SAMPLE_DATA).unwrap();
+    let seat = challenge.seat_map().missing_seat().unwrap();
 
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
+    assert_eq!((seat.row(), seat.column(), seat.seat_id()), (0, 2, 2));
 }
 
 #[test]
-fn sample_data_for_gold_solution_has_ok_result() {
-    let mut challenge = Day05::new(SAMPLE_DATA).unwrap();
+fn report_lists_gaps_and_seat() {
+    let challenge = Day05::new(GOLD_SAMPLE_DATA).unwrap();
 
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_gold().unwrap();
+    assert_eq!(
+        challenge.report(),
+        vec![
+            "Missing between boarding passes: 1 seat at row 0, column 2 (ID 2)",
+            "Missing at the back: 1019 seats from row 0, column 5 (ID 5) \
+             to row 127, column 7 (ID 1023)",
+            "Your seat: row 0, column 2 (ID 2)",
+        ]
+    );
 }
 
 #[test]
-fn sample_data_for_gold_solution_has_ok_result_of() {
-    let mut challenge = Day05::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_gold();
+fn passes_are_inspected() {
+    let challenge = Day05::new(SAMPLE_DATA).unwrap();
 
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
+    assert_eq!(
+        challenge.inspect(),
+        vec![
+            ("Boarding passes", "4".into()),
+            ("Plane", "128 rows of 8 seats".into()),
+            ("Seats taken", "4 of 1024".into()),
+            ("Seat IDs", "119 to 820".into()),
+        ]
+    );
 }
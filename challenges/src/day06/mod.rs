@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day06 {
@@ -14,6 +14,8 @@ impl Day06 {
     }
 }
 
+impl ChallengeInput for Day06 {}
+
 impl SilverChallenge for Day06 {
     type Answer = ();
     type Error = &'static str;
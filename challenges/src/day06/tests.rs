@@ -1,44 +1,10 @@
 // import everything from the parent module file (in this case mod.rs)
 use super::*;
 
-const SAMPLE_DATA: &'static str = "<replace this with sample data from challenge>";
+const SAMPLE_DATA: &str = "<replace this with sample data from challenge>";
 
-#[test]
-fn sample_data_builds_ok() {
-    // unwrap will fail the test here if the day input parsing returned an Err
-    Day06::new(SAMPLE_DATA).unwrap();
-}
-
-#[test]
-fn sample_data_for_silver_solution_has_ok_result() {
-    let mut challenge = Day06::new(SAMPLE_DATA).unwrap();
-
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_silver().unwrap();
-}
-
-#[test]
-fn sample_data_for_silver_solution_has_ok_result_of() {
-    let mut challenge = Day06::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_silver();
-
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
-}
-
-#[test]
-fn sample_data_for_gold_solution_has_ok_result() {
-    let mut challenge = Day06::new(SAMPLE_DATA).unwrap();
-
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_gold().unwrap();
-}
-
-#[test]
-fn sample_data_for_gold_solution_has_ok_result_of() {
-    let mut challenge = Day06::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_gold();
-
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
-}
+// replace the unit values `()` with the correct results based on the sample data,
+// more samples can be added and either part left out where a sample only covers one
+sample_tests!(Day06, [
+    { data: SAMPLE_DATA, silver: Ok(()), gold: Ok(()) },
+]);
@@ -1,19 +1,39 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, Inspect, SilverChallenge};
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day07 {
-    data: String,
+    // the colors each color of bag directly contains, by the color containing them
+    rules: HashMap<String, Vec<String>>,
 }
 
 impl Day07 {
     pub fn new(data: &str) -> Result<Self, String> {
-        Ok(Self { data: data.into() })
+        let rules = data
+            .lines()
+            .enumerate()
+            .map(|(index, line)| match parse_rule(line) {
+                Some((color, inner_colors)) => Ok((
+                    color.to_string(),
+                    inner_colors.into_iter().map(String::from).collect(),
+                )),
+                None => Err(format!(
+                    "Invalid bag rule on line {}: {:?}",
+                    index + 1,
+                    line
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { rules })
     }
 }
 
+impl ChallengeInput for Day07 {}
+
 impl SilverChallenge for Day07 {
     type Answer = ();
     type Error = &'static str;
@@ -37,3 +57,79 @@ impl GoldChallenge for Day07 {
         Err("NYI")
     }
 }
+
+// splits a rule such as `light red bags contain 1 bright white bag, 2 muted yellow bags.`
+// into its outer color and the colors it directly contains
+fn parse_rule(rule: &str) -> Option<(&str, Vec<&str>)> {
+    let (color, contents) = rule.split_once(" bags contain ")?;
+    let contents = contents.trim_end_matches('.');
+
+    let inner_colors = match contents {
+        "no other bags" => Vec::new(),
+        _ => contents
+            .split(", ")
+            .map(|content| {
+                let content = content.trim_end_matches(" bags").trim_end_matches(" bag");
+                content.split_once(' ').map(|(_, color)| color)
+            })
+            .collect::<Option<_>>()?,
+    };
+
+    Some((color, inner_colors))
+}
+
+fn nesting_depth<'a>(
+    color: &'a str,
+    rules: &'a HashMap<String, Vec<String>>,
+    depths: &mut HashMap<&'a str, Option<usize>>,
+) -> Option<usize> {
+    match depths.get(color) {
+        Some(Some(depth)) => return Some(*depth),
+        // still being visited further up, so the rules contain a cycle
+        Some(None) => return None,
+        None => {}
+    }
+
+    depths.insert(color, None);
+    let mut depth = 0;
+    for inner_color in rules.get(color).into_iter().flatten() {
+        depth = depth.max(nesting_depth(inner_color.as_str(), rules, depths)? + 1);
+    }
+    depths.insert(color, Some(depth));
+
+    Some(depth)
+}
+
+// colors only found inside other bags have no rule of their own, but are colors too
+impl Inspect for Day07 {
+    fn inspect(&self) -> Vec<(&'static str, String)> {
+        let colors = self
+            .rules
+            .keys()
+            .chain(self.rules.values().flatten())
+            .collect::<HashSet<_>>();
+        let empty_colors = self
+            .rules
+            .values()
+            .filter(|inner_colors| inner_colors.is_empty());
+        let mut depths = HashMap::new();
+        let max_nesting_depth = self
+            .rules
+            .keys()
+            .map(|color| nesting_depth(color.as_str(), &self.rules, &mut depths))
+            .collect::<Option<Vec<_>>>()
+            .map(|depths| depths.into_iter().max().unwrap_or_default());
+
+        vec![
+            ("Bag colors", colors.len().to_string()),
+            ("Empty bag colors", empty_colors.count().to_string()),
+            (
+                "Max nesting depth",
+                match max_nesting_depth {
+                    Some(depth) => depth.to_string(),
+                    None => "unbounded, rules contain a cycle".into(),
+                },
+            ),
+        ]
+    }
+}
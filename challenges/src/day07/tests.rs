@@ -1,44 +1,66 @@
 // import everything from the parent module file (in this case mod.rs)
 use super::*;
 
-const SAMPLE_DATA: &'static str = "<replace this with sample data from challenge>";
+const SAMPLE_DATA: &str = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
 
-#[test]
-fn sample_data_builds_ok() {
-    // unwrap will fail the test here if the day input parsing returned an Err
-    Day07::new(SAMPLE_DATA).unwrap();
-}
+// replace the unit values `()` with the correct results based on the sample data,
+// more samples can be added and either part left out where a sample only covers one
+sample_tests!(Day07, [
+    { data: SAMPLE_DATA, silver: Ok(()), gold: Ok(()) },
+]);
 
 #[test]
-fn sample_data_for_silver_solution_has_ok_result() {
-    let mut challenge = Day07::new(SAMPLE_DATA).unwrap();
+fn sample_data_is_inspected() {
+    let challenge = Day07::new(SAMPLE_DATA).unwrap();
 
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_silver().unwrap();
+    assert_eq!(
+        challenge.inspect(),
+        vec![
+            ("Bag colors", "9".into()),
+            ("Empty bag colors", "2".into()),
+            ("Max nesting depth", "4".into()),
+        ]
+    );
 }
 
 #[test]
-fn sample_data_for_silver_solution_has_ok_result_of() {
-    let mut challenge = Day07::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_silver();
+fn contained_colors_without_rules_are_counted() {
+    let challenge =
+        Day07::new("light red bags contain 1 bright white bag, 2 muted yellow bags.").unwrap();
 
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
+    assert_eq!(challenge.inspect()[0], ("Bag colors", "3".into()));
+    assert_eq!(challenge.inspect()[2], ("Max nesting depth", "1".into()));
 }
 
 #[test]
-fn sample_data_for_gold_solution_has_ok_result() {
-    let mut challenge = Day07::new(SAMPLE_DATA).unwrap();
-
-    // unwrap will fail the test here if the silver challenge returned an Err
-    challenge.attempt_gold().unwrap();
+fn unrecognized_rules_are_rejected() {
+    assert_eq!(
+        Day07::new("light red bags contain 1 bright white bag.\nshiny gold bags"),
+        Err("Invalid bag rule on line 2: \"shiny gold bags\"".into())
+    );
 }
 
 #[test]
-fn sample_data_for_gold_solution_has_ok_result_of() {
-    let mut challenge = Day07::new(SAMPLE_DATA).unwrap();
-    let result = challenge.attempt_gold();
+fn cyclic_rules_are_inspected() {
+    let challenge = Day07::new(
+        "light red bags contain 1 bright white bag.
+bright white bags contain 2 light red bags.",
+    )
+    .unwrap();
 
-    // replace the unit value `()` with the correct result based on the sample data
-    assert_eq!(result, Ok(()));
+    assert_eq!(
+        challenge.inspect()[2],
+        (
+            "Max nesting depth",
+            "unbounded, rules contain a cycle".into()
+        )
+    );
 }
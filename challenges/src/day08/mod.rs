@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day08 {
@@ -14,6 +14,8 @@ impl Day08 {
     }
 }
 
+impl ChallengeInput for Day08 {}
+
 impl SilverChallenge for Day08 {
     type Answer = ();
     type Error = &'static str;
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day09 {
@@ -14,6 +14,8 @@ impl Day09 {
     }
 }
 
+impl ChallengeInput for Day09 {}
+
 impl SilverChallenge for Day09 {
     type Answer = ();
     type Error = &'static str;
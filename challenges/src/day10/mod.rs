@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day10 {
@@ -14,6 +14,8 @@ impl Day10 {
     }
 }
 
+impl ChallengeInput for Day10 {}
+
 impl SilverChallenge for Day10 {
     type Answer = ();
     type Error = &'static str;
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day11 {
@@ -14,6 +14,8 @@ impl Day11 {
     }
 }
 
+impl ChallengeInput for Day11 {}
+
 impl SilverChallenge for Day11 {
     type Answer = ();
     type Error = &'static str;
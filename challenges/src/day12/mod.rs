@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day12 {
@@ -14,6 +14,8 @@ impl Day12 {
     }
 }
 
+impl ChallengeInput for Day12 {}
+
 impl SilverChallenge for Day12 {
     type Answer = ();
     type Error = &'static str;
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day13 {
@@ -14,6 +14,8 @@ impl Day13 {
     }
 }
 
+impl ChallengeInput for Day13 {}
+
 impl SilverChallenge for Day13 {
     type Answer = ();
     type Error = &'static str;
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day14 {
@@ -14,6 +14,8 @@ impl Day14 {
     }
 }
 
+impl ChallengeInput for Day14 {}
+
 impl SilverChallenge for Day14 {
     type Answer = ();
     type Error = &'static str;
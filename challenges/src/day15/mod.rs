@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day15 {
@@ -14,6 +14,8 @@ impl Day15 {
     }
 }
 
+impl ChallengeInput for Day15 {}
+
 impl SilverChallenge for Day15 {
     type Answer = ();
     type Error = &'static str;
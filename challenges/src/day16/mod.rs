@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day16 {
@@ -14,6 +14,8 @@ impl Day16 {
     }
 }
 
+impl ChallengeInput for Day16 {}
+
 impl SilverChallenge for Day16 {
     type Answer = ();
     type Error = &'static str;
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day17 {
@@ -14,6 +14,8 @@ impl Day17 {
     }
 }
 
+impl ChallengeInput for Day17 {}
+
 impl SilverChallenge for Day17 {
     type Answer = ();
     type Error = &'static str;
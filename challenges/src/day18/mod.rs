@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day18 {
@@ -14,6 +14,8 @@ impl Day18 {
     }
 }
 
+impl ChallengeInput for Day18 {}
+
 impl SilverChallenge for Day18 {
     type Answer = ();
     type Error = &'static str;
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day19 {
@@ -14,6 +14,8 @@ impl Day19 {
     }
 }
 
+impl ChallengeInput for Day19 {}
+
 impl SilverChallenge for Day19 {
     type Answer = ();
     type Error = &'static str;
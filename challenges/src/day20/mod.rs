@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day20 {
@@ -14,6 +14,8 @@ impl Day20 {
     }
 }
 
+impl ChallengeInput for Day20 {}
+
 impl SilverChallenge for Day20 {
     type Answer = ();
     type Error = &'static str;
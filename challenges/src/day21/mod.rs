@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day21 {
@@ -14,6 +14,8 @@ impl Day21 {
     }
 }
 
+impl ChallengeInput for Day21 {}
+
 impl SilverChallenge for Day21 {
     type Answer = ();
     type Error = &'static str;
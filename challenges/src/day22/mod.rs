@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day22 {
@@ -14,6 +14,8 @@ impl Day22 {
     }
 }
 
+impl ChallengeInput for Day22 {}
+
 impl SilverChallenge for Day22 {
     type Answer = ();
     type Error = &'static str;
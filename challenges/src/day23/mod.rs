@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day23 {
@@ -14,6 +14,8 @@ impl Day23 {
     }
 }
 
+impl ChallengeInput for Day23 {}
+
 impl SilverChallenge for Day23 {
     type Answer = ();
     type Error = &'static str;
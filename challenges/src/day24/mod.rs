@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day24 {
@@ -14,6 +14,8 @@ impl Day24 {
     }
 }
 
+impl ChallengeInput for Day24 {}
+
 impl SilverChallenge for Day24 {
     type Answer = ();
     type Error = &'static str;
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{GoldChallenge, SilverChallenge};
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Day25 {
@@ -14,6 +14,8 @@ impl Day25 {
     }
 }
 
+impl ChallengeInput for Day25 {}
+
 impl SilverChallenge for Day25 {
     type Answer = ();
     type Error = &'static str;
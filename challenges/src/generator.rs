@@ -0,0 +1,39 @@
+use std::ops::RangeInclusive;
+
+// small deterministic generator (splitmix64) so synthesized inputs are
+// reproducible from a seed without pulling in an external crate
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        value ^ (value >> 31)
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        start + self.next_u64() % (end - start + 1)
+    }
+
+    pub fn index(&mut self, length: usize) -> usize {
+        self.range(0..=length as u64 - 1) as usize
+    }
+
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.next_u64() % denominator < numerator
+    }
+
+    pub fn lowercase_letter(&mut self) -> char {
+        (b'a' + self.range(0..=25) as u8) as char
+    }
+}
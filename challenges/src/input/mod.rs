@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests;
+
+use crate::ChallengeInput;
+
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+// Cleans up differences in how input files were saved before any day parses them:
+// strips a leading byte order mark, converts CRLF line endings to LF, and unless the
+// day is whitespace sensitive, trims trailing whitespace from every line and the end.
+pub fn normalize_input(data: &str, whitespace_sensitive: bool) -> Result<String, String> {
+    let data = data.strip_prefix(BYTE_ORDER_MARK).unwrap_or(data);
+    let data = data.replace("\r\n", "\n");
+
+    let data = match whitespace_sensitive {
+        true => data,
+        false => data
+            .lines()
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim_end()
+            .to_string(),
+    };
+
+    match data.trim().is_empty() {
+        true => Err("Input data is empty.".into()),
+        false => Ok(data),
+    }
+}
+
+// input for a day, whose trailing whitespace is also kept when the day declares it meaningful
+pub fn normalize_challenge_input<D: ChallengeInput>(
+    data: &str,
+    preserve_whitespace: bool,
+) -> Result<String, String> {
+    normalize_input(data, preserve_whitespace || D::WHITESPACE_SENSITIVE)
+}
@@ -0,0 +1,72 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+#[test]
+fn byte_order_mark_is_stripped() {
+    assert_eq!(
+        normalize_input("\u{feff}1721\n979", false),
+        Ok("1721\n979".into())
+    );
+    assert_eq!(
+        normalize_input("\u{feff}1721\n979", true),
+        Ok("1721\n979".into())
+    );
+}
+
+#[test]
+fn crlf_line_endings_become_lf() {
+    assert_eq!(
+        normalize_input("1721\r\n979\r\n", true),
+        Ok("1721\n979\n".into())
+    );
+}
+
+#[test]
+fn trailing_whitespace_is_trimmed() {
+    assert_eq!(
+        normalize_input("ecl:gry  \r\n\r\nhcl:#fffffd\t\n\n\n", false),
+        Ok("ecl:gry\n\nhcl:#fffffd".into())
+    );
+}
+
+#[test]
+fn trailing_whitespace_is_kept_when_whitespace_sensitive() {
+    assert_eq!(
+        normalize_input("..#  \n#..\n\n", true),
+        Ok("..#  \n#..\n\n".into())
+    );
+}
+
+#[test]
+fn empty_input_is_rejected() {
+    assert!(normalize_input("", false).is_err());
+    assert!(normalize_input("\u{feff}\r\n  \n", false).is_err());
+    assert!(normalize_input("\r\n\t", true).is_err());
+}
+
+// a day whose input has meaningful trailing whitespace, such as a grid with open edges
+struct PaddedGrid;
+
+impl ChallengeInput for PaddedGrid {
+    const WHITESPACE_SENSITIVE: bool = true;
+}
+
+struct PlainList;
+
+impl ChallengeInput for PlainList {}
+
+#[test]
+fn whitespace_sensitive_days_keep_trailing_whitespace() {
+    assert_eq!(
+        normalize_challenge_input::<PaddedGrid>("#. \r\n .#\n", false),
+        Ok("#. \n .#\n".into())
+    );
+    assert_eq!(
+        normalize_challenge_input::<PlainList>("#. \r\n .#\n", false),
+        Ok("#.\n .#".into())
+    );
+    assert_eq!(
+        normalize_challenge_input::<PlainList>("#. \r\n .#\n", true),
+        Ok("#. \n .#\n".into())
+    );
+}
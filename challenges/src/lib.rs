@@ -1,55 +1,185 @@
+#[cfg(test)]
+#[macro_use]
+mod testing;
+#[cfg(test)]
+#[macro_use]
+mod properties;
+
+#[cfg(feature = "day01")]
 mod day01;
+#[cfg(feature = "day02")]
 mod day02;
+#[cfg(feature = "day03")]
 mod day03;
+#[cfg(feature = "day04")]
 mod day04;
+#[cfg(feature = "day05")]
 mod day05;
+#[cfg(feature = "day06")]
 mod day06;
+#[cfg(feature = "day07")]
 mod day07;
+#[cfg(feature = "day08")]
 mod day08;
+#[cfg(feature = "day09")]
 mod day09;
+#[cfg(feature = "day10")]
 mod day10;
+#[cfg(feature = "day11")]
 mod day11;
+#[cfg(feature = "day12")]
 mod day12;
+#[cfg(feature = "day13")]
 mod day13;
+#[cfg(feature = "day14")]
 mod day14;
+#[cfg(feature = "day15")]
 mod day15;
+#[cfg(feature = "day16")]
 mod day16;
+#[cfg(feature = "day17")]
 mod day17;
+#[cfg(feature = "day18")]
 mod day18;
+#[cfg(feature = "day19")]
 mod day19;
+#[cfg(feature = "day20")]
 mod day20;
+#[cfg(feature = "day21")]
 mod day21;
+#[cfg(feature = "day22")]
 mod day22;
+#[cfg(feature = "day23")]
 mod day23;
+#[cfg(feature = "day24")]
 mod day24;
+#[cfg(feature = "day25")]
 mod day25;
 
-pub use day01::Day01;
-pub use day02::Day02;
-pub use day03::Day03;
-pub use day04::Day04;
-pub use day05::Day05;
+mod catalog;
+// only used by days with an input generator, which may not be compiled in
+#[allow(dead_code)]
+mod generator;
+mod input;
+
+#[cfg(feature = "day01")]
+pub use day01::{find_k_sum, find_k_sum_indices, Day01, ExpenseEntry};
+#[cfg(feature = "day02")]
+pub use day02::{
+    CountPolicy, Day02, LineReport, PasswordEntry, PasswordPolicy, PolicyExpression,
+    PolicyViolation, PositionPolicy, COUNT_POLICY, POSITION_POLICY,
+};
+#[cfg(feature = "day03")]
+pub use day03::{Day03, PathRender, Slope, SlopeTrees, Square, TreeMap, GOLD_SLOPES, SILVER_SLOPE};
+#[cfg(feature = "day04")]
+pub use day04::{
+    format_passports, parse_passports, Day04, FieldSpec, Location, Passport, PassportField, Rule,
+    Schema, Unit, Violation, GOLD_SCHEMA, SILVER_SCHEMA,
+};
+#[cfg(feature = "day05")]
+pub use day05::{BoardingPass, Day05, Gap, GapPosition, PlaneGeometry, SeatMap, DEFAULT_GEOMETRY};
+#[cfg(feature = "day06")]
 pub use day06::Day06;
+#[cfg(feature = "day07")]
 pub use day07::Day07;
+#[cfg(feature = "day08")]
 pub use day08::Day08;
+#[cfg(feature = "day09")]
 pub use day09::Day09;
+#[cfg(feature = "day10")]
 pub use day10::Day10;
+#[cfg(feature = "day11")]
 pub use day11::Day11;
+#[cfg(feature = "day12")]
 pub use day12::Day12;
+#[cfg(feature = "day13")]
 pub use day13::Day13;
+#[cfg(feature = "day14")]
 pub use day14::Day14;
+#[cfg(feature = "day15")]
 pub use day15::Day15;
+#[cfg(feature = "day16")]
 pub use day16::Day16;
+#[cfg(feature = "day17")]
 pub use day17::Day17;
+#[cfg(feature = "day18")]
 pub use day18::Day18;
+#[cfg(feature = "day19")]
 pub use day19::Day19;
+#[cfg(feature = "day20")]
 pub use day20::Day20;
+#[cfg(feature = "day21")]
 pub use day21::Day21;
+#[cfg(feature = "day22")]
 pub use day22::Day22;
+#[cfg(feature = "day23")]
 pub use day23::Day23;
+#[cfg(feature = "day24")]
 pub use day24::Day24;
+#[cfg(feature = "day25")]
 pub use day25::Day25;
 
+pub use catalog::puzzle_title;
+pub use input::{normalize_challenge_input, normalize_input};
+
+// Generates the lookups of days by number from a single table of every day, with the
+// cargo feature it is compiled in by and its challenge, so that no day can be missed.
+macro_rules! days {
+    ($($day:literal => $feature:literal $challenge:ident),* $(,)?) => {
+        // whether the day was compiled in through its `dayNN` cargo feature
+        pub fn is_day_available(day: u32) -> bool {
+            match day {
+                $($day => cfg!(feature = $feature),)*
+                _ => false,
+            }
+        }
+
+        // normalizes input for the day, keeping trailing whitespace if the day declares
+        // it meaningful, where days not compiled in have nothing to declare
+        pub fn normalize_day_input(
+            day: u32,
+            data: &str,
+            preserve_whitespace: bool,
+        ) -> Result<String, String> {
+            match day {
+                $(
+                    #[cfg(feature = $feature)]
+                    $day => normalize_challenge_input::<$challenge>(data, preserve_whitespace),
+                )*
+                _ => normalize_input(data, preserve_whitespace),
+            }
+        }
+    };
+}
+
+days! {
+    1 => "day01" Day01,
+    2 => "day02" Day02,
+    3 => "day03" Day03,
+    4 => "day04" Day04,
+    5 => "day05" Day05,
+    6 => "day06" Day06,
+    7 => "day07" Day07,
+    8 => "day08" Day08,
+    9 => "day09" Day09,
+    10 => "day10" Day10,
+    11 => "day11" Day11,
+    12 => "day12" Day12,
+    13 => "day13" Day13,
+    14 => "day14" Day14,
+    15 => "day15" Day15,
+    16 => "day16" Day16,
+    17 => "day17" Day17,
+    18 => "day18" Day18,
+    19 => "day19" Day19,
+    20 => "day20" Day20,
+    21 => "day21" Day21,
+    22 => "day22" Day22,
+    23 => "day23" Day23,
+    24 => "day24" Day24,
+    25 => "day25" Day25,
+}
+
 pub trait SilverChallenge {
     type Answer;
     type Error;
@@ -69,3 +199,23 @@ pub trait GoldChallenge {
         Self::Answer: std::fmt::Debug,
         Self::Error: std::fmt::Debug;
 }
+
+pub trait ChallengeInput {
+    /// Whether trailing whitespace in the input is meaningful, so is kept when normalizing it.
+    const WHITESPACE_SENSITIVE: bool = false;
+}
+
+pub trait InputGenerator {
+    /// Synthesizes a valid puzzle input of `size` entries, reproducible for a given `seed`.
+    fn generate_input(size: usize, seed: u64) -> String;
+}
+
+pub trait Inspect {
+    /// Statistics describing the structure of the parsed input, as labelled values.
+    fn inspect(&self) -> Vec<(&'static str, String)>;
+}
+
+pub trait Report {
+    /// Details behind the answers for verbose output, such as which entries fail and why.
+    fn report(&self) -> Vec<String>;
+}
@@ -0,0 +1,254 @@
+// Strategies generating puzzle inputs of each shape, along with properties shared
+// between days which are checked against those generated inputs with proptest.
+//
+// Every strategy produces input text as it would be read from a normalized input
+// file, so it can be given to a day's constructor as is.
+use proptest::collection::{btree_map, hash_set, vec};
+use proptest::prelude::*;
+
+fn lines(lines: Vec<String>) -> String {
+    lines.join("\n")
+}
+
+// expense reports for day 1, one number per line
+pub fn expense_report() -> impl Strategy<Value = String> {
+    vec(1u64..=2019, 2..200)
+        .prop_map(|entries| lines(entries.iter().map(|entry| entry.to_string()).collect()))
+}
+
+// password policies and passwords for day 2, e.g. `1-3 a: abcde`
+pub fn password_entries() -> impl Strategy<Value = String> {
+    let entry = (1usize..=10, 0usize..=10, "[a-z]", "[a-z]{1,20}").prop_map(
+        |(low, spread, letter, password)| {
+            format!("{}-{} {}: {}", low, low + spread, letter, password)
+        },
+    );
+
+    vec(entry, 1..100).prop_map(lines)
+}
+
+fn passport_field() -> impl Strategy<Value = (&'static str, String)> {
+    prop_oneof![
+        (1900u32..=2030).prop_map(|year| ("byr", year.to_string())),
+        (2000u32..=2030).prop_map(|year| ("iyr", year.to_string())),
+        (2010u32..=2040).prop_map(|year| ("eyr", year.to_string())),
+        (50u32..=200, prop_oneof!["cm", "in", ""])
+            .prop_map(|(height, unit)| ("hgt", format!("{}{}", height, unit))),
+        "#?[0-9a-f]{6}".prop_map(|color| ("hcl", color)),
+        prop_oneof!["amb", "blu", "brn", "gry", "grn", "hzl", "oth", "xyz"]
+            .prop_map(|color| ("ecl", color)),
+        "[0-9]{8,10}".prop_map(|id| ("pid", id)),
+        "[0-9]{2,3}".prop_map(|id| ("cid", id)),
+    ]
+}
+
+// passport batches for day 4, `key:value` pairs separated by spaces or newlines
+// with passports separated by blank lines, where any field may be missing
+pub fn passport_batch() -> impl Strategy<Value = String> {
+    let passport = btree_map(passport_field(), any::<bool>(), 1..8).prop_map(|fields| {
+        let mut passport = String::new();
+        for ((key, value), newline) in fields {
+            if !passport.is_empty() {
+                passport.push(if newline { '\n' } else { ' ' });
+            }
+            passport.push_str(&format!("{}:{}", key, value));
+        }
+        passport
+    });
+
+    vec(passport, 1..30).prop_map(|passports| passports.join("\n\n"))
+}
+
+// boarding passes for day 5, e.g. `FBFBBFFRLR`, each seat appearing at most once
+pub fn boarding_passes() -> impl Strategy<Value = String> {
+    hash_set("[FB]{7}[LR]{3}", 1..200).prop_map(|passes| lines(passes.into_iter().collect()))
+}
+
+// bag rules for day 7, where bags only ever contain bags declared after them
+// so that the rules never describe a bag that eventually contains itself
+pub fn bag_rules() -> impl Strategy<Value = String> {
+    hash_set("[a-z]{3,6} [a-z]{3,6}", 1..30)
+        .prop_flat_map(|colors| {
+            let colors = colors.into_iter().collect::<Vec<_>>();
+            let contents = vec(
+                vec((1u32..=5, any::<prop::sample::Index>()), 0..4),
+                colors.len(),
+            );
+            (Just(colors), contents)
+        })
+        .prop_map(|(colors, contents)| {
+            let rules = colors
+                .iter()
+                .zip(contents)
+                .enumerate()
+                .map(|(index, (color, content))| {
+                    let inner_colors = &colors[index + 1..];
+                    let mut inner_bags = Vec::new();
+                    if !inner_colors.is_empty() {
+                        for (count, inner_index) in content {
+                            let inner_color = inner_index.get(inner_colors);
+                            if !inner_bags
+                                .iter()
+                                .any(|(_, existing)| *existing == inner_color)
+                            {
+                                inner_bags.push((count, inner_color));
+                            }
+                        }
+                    }
+
+                    match inner_bags.is_empty() {
+                        true => format!("{} bags contain no other bags.", color),
+                        false => format!(
+                            "{} bags contain {}.",
+                            color,
+                            inner_bags
+                                .iter()
+                                .map(|(count, inner_color)| match count {
+                                    1 => format!("1 {} bag", inner_color),
+                                    _ => format!("{} {} bags", count, inner_color),
+                                })
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    }
+                })
+                .collect();
+            lines(rules)
+        })
+}
+
+// boot code for day 8, e.g. `acc +1` or `jmp -4`
+pub fn boot_code() -> impl Strategy<Value = String> {
+    let instruction = (prop_oneof!["nop", "acc", "jmp"], -99i32..=99)
+        .prop_map(|(operation, argument)| format!("{} {:+}", operation, argument));
+
+    vec(instruction, 1..200).prop_map(lines)
+}
+
+// Checks that a day parses every generated input, and that serializing the parsed
+// day with its `Display` implementation parses back into an equal day. `Display`
+// has to write the parsed structures back out as puzzle input, since writing the
+// raw input kept by a day which has no parser yet would only show that the input
+// equals itself, so stub days are left without this property until they parse.
+macro_rules! round_trip_property {
+    ($day:ident, $strategy:expr) => {
+        proptest::proptest! {
+            #[test]
+            fn parse_then_serialize_round_trips(data in $strategy) {
+                let parsed = $day::new(&data).unwrap();
+                let reparsed = $day::new(&parsed.to_string()).unwrap();
+                proptest::prop_assert_eq!(parsed, reparsed);
+            }
+        }
+    };
+}
+
+// Checks that two ways of solving the same part agree for every generated input,
+// usually a brute-force variant checking the result of an optimized one.
+macro_rules! equivalent_variants_property {
+    ($day:ident, $strategy:expr, $brute_force:ident, $optimized:ident) => {
+        proptest::proptest! {
+            #[test]
+            fn brute_force_variant_equals_optimized_variant(data in $strategy) {
+                let challenge = $day::new(&data).unwrap();
+                proptest::prop_assert_eq!(challenge.$brute_force(), challenge.$optimized());
+            }
+        }
+    };
+}
+
+mod tests {
+    use super::*;
+
+    // stands in for a day to check the shared properties themselves
+    #[derive(Debug, PartialEq)]
+    struct NumberList {
+        numbers: Vec<u64>,
+    }
+
+    impl NumberList {
+        fn new(data: &str) -> Result<Self, String> {
+            let numbers = data
+                .lines()
+                .map(|line| line.parse().map_err(|_| format!("Bad number: {}", line)))
+                .collect::<Result<_, _>>()?;
+            Ok(Self { numbers })
+        }
+
+        fn brute_force_largest(&self) -> Option<u64> {
+            let mut largest = None;
+            for number in &self.numbers {
+                if largest.is_none_or(|largest| *number > largest) {
+                    largest = Some(*number);
+                }
+            }
+            largest
+        }
+
+        fn optimized_largest(&self) -> Option<u64> {
+            self.numbers.iter().max().copied()
+        }
+    }
+
+    impl std::fmt::Display for NumberList {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let lines = self.numbers.iter().map(|number| number.to_string());
+            write!(f, "{}", lines.collect::<Vec<_>>().join("\n"))
+        }
+    }
+
+    round_trip_property!(NumberList, expense_report());
+    equivalent_variants_property!(
+        NumberList,
+        expense_report(),
+        brute_force_largest,
+        optimized_largest
+    );
+
+    proptest! {
+        #[test]
+        fn password_entries_have_policy_within_password_alphabet(data in password_entries()) {
+            for line in data.lines() {
+                let (policy, password) = line.split_at(line.find(": ").unwrap());
+                prop_assert!(policy.contains('-'));
+                prop_assert!(password[2..].chars().all(|c| c.is_ascii_lowercase()));
+            }
+        }
+
+        #[test]
+        fn passports_are_separated_by_blank_lines(data in passport_batch()) {
+            for passport in data.split("\n\n") {
+                prop_assert!(!passport.is_empty());
+                prop_assert!(passport.split_whitespace().all(|field| field.contains(':')));
+            }
+        }
+
+        #[test]
+        fn boarding_passes_are_ten_characters(data in boarding_passes()) {
+            prop_assert!(data.lines().all(|pass| pass.len() == 10));
+        }
+
+        #[test]
+        fn bag_rules_only_contain_later_bags(data in bag_rules()) {
+            let colors = data
+                .lines()
+                .map(|rule| rule.split(" bags contain ").next().unwrap())
+                .collect::<Vec<_>>();
+            for (index, rule) in data.lines().enumerate() {
+                for earlier_color in &colors[..=index] {
+                    let contents = rule.split(" bags contain ").nth(1).unwrap();
+                    let inner_bag = format!(" {} bag", earlier_color);
+                    prop_assert!(!contents.contains(&inner_bag));
+                }
+            }
+        }
+
+        #[test]
+        fn boot_code_arguments_are_signed(data in boot_code()) {
+            for instruction in data.lines() {
+                let argument = instruction.split(' ').nth(1).unwrap();
+                prop_assert!(argument.starts_with('+') || argument.starts_with('-'));
+            }
+        }
+    }
+}
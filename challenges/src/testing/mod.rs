@@ -0,0 +1,113 @@
+// Generates the sample tests shared by every day from a table of samples.
+//
+// Each sample gives its data along with the expected silver and/or gold results,
+// so samples which only apply to one part can leave the other out. Results for the
+// real puzzle input can optionally be given too, and are checked as regressions
+// against the file of that name in the `input` directory whenever it exists, or
+// against data given inline in place of the file.
+//
+// sample_tests!(Day01, [
+//     { data: SAMPLE_DATA, silver: Ok(514579), gold: Ok(241861950) },
+//     { data: SILVER_ONLY_SAMPLE_DATA, silver: Ok(1) },
+// ], input: { file: "day01.txt", silver: Ok(898299), gold: Ok(143933922) });
+macro_rules! sample_tests {
+    (
+        $day:ident,
+        [$({
+            data: $data:expr
+            $(, silver: $silver:expr)?
+            $(, gold: $gold:expr)?
+            $(,)?
+        }),* $(,)?]
+        $(, input: {
+            $source:ident: $input:tt
+            $(, silver: $input_silver:expr)?
+            $(, gold: $input_gold:expr)?
+            $(,)?
+        })?
+        $(,)?
+    ) => {
+        #[test]
+        fn sample_data_builds_ok() {
+            let mut sample = 0;
+            $(
+                sample += 1;
+                // will fail the test here if the day input parsing returned an Err
+                if let Err(error) = $day::new($data) {
+                    panic!("sample {} failed to build: {:?}", sample, error);
+                }
+            )*
+            let _ = sample;
+        }
+
+        #[test]
+        fn sample_data_for_silver_solution_has_ok_result_of() {
+            let mut sample = 0;
+            $(
+                sample += 1;
+                $(
+                    let mut challenge = $day::new($data).unwrap();
+                    assert_eq!(challenge.attempt_silver(), $silver, "silver result of sample {}", sample);
+                )?
+            )*
+            let _ = sample;
+        }
+
+        #[test]
+        fn sample_data_for_gold_solution_has_ok_result_of() {
+            let mut sample = 0;
+            $(
+                sample += 1;
+                $(
+                    let mut challenge = $day::new($data).unwrap();
+                    assert_eq!(challenge.attempt_gold(), $gold, "gold result of sample {}", sample);
+                )?
+            )*
+            let _ = sample;
+        }
+
+        $(
+            fn input_data() -> Option<String> {
+                let data = sample_tests!(@input $source $input)?;
+                Some($crate::normalize_challenge_input::<$day>(&data, false).unwrap())
+            }
+
+            $(
+                #[test]
+                fn input_data_for_silver_solution_has_ok_result_of() {
+                    if let Some(data) = input_data() {
+                        let mut challenge = $day::new(&data).unwrap();
+                        assert_eq!(challenge.attempt_silver(), $input_silver);
+                    }
+                }
+            )?
+
+            $(
+                #[test]
+                fn input_data_for_gold_solution_has_ok_result_of() {
+                    if let Some(data) = input_data() {
+                        let mut challenge = $day::new(&data).unwrap();
+                        assert_eq!(challenge.attempt_gold(), $input_gold);
+                    }
+                }
+            )?
+        )?
+    };
+    (@input file $input_file:literal) => {{
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../input/", $input_file);
+        match std::fs::read_to_string(path) {
+            Ok(data) => Some(data),
+            Err(_) => {
+                eprintln!("skipping regression, no input file at {}", path);
+                None
+            }
+        }
+    }};
+    (@input data $input_data:tt) => {
+        Some($input_data.to_string())
+    };
+}
+
+// declared after the macro, which is only in scope for modules following it
+#[cfg(test)]
+mod tests;
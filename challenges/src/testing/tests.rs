@@ -0,0 +1,43 @@
+// exercises every form of the macro against a challenge with known answers
+use crate::{ChallengeInput, GoldChallenge, SilverChallenge};
+
+#[derive(Debug)]
+struct LineCounter {
+    data: String,
+}
+
+impl LineCounter {
+    fn new(data: &str) -> Result<Self, String> {
+        Ok(Self { data: data.into() })
+    }
+}
+
+impl ChallengeInput for LineCounter {}
+
+impl SilverChallenge for LineCounter {
+    type Answer = usize;
+    type Error = &'static str;
+
+    fn attempt_silver(&mut self) -> Result<Self::Answer, Self::Error> {
+        Ok(self.data.lines().count())
+    }
+}
+
+impl GoldChallenge for LineCounter {
+    type Answer = usize;
+    type Error = &'static str;
+
+    fn attempt_gold(&mut self) -> Result<Self::Answer, Self::Error> {
+        match self.data.lines().filter(|line| line.is_empty()).count() {
+            0 => Err("no blank lines"),
+            blank_lines => Ok(blank_lines),
+        }
+    }
+}
+
+sample_tests!(LineCounter, [
+    { data: "a\n\nb", silver: Ok(3), gold: Ok(1) },
+    { data: "a\nb", silver: Ok(2), gold: Err("no blank lines") },
+    { data: "a", silver: Ok(1) },
+    { data: "\n\n", gold: Ok(2) },
+], input: { data: "a\nb\nc\n", silver: Ok(3), gold: Err("no blank lines") });
@@ -0,0 +1,6 @@
+#![no_main]
+use advent_of_code_2020_challenges::Day12;
+use advent_of_code_2020_fuzz::fuzz_challenge;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| fuzz_challenge(Day12::new, data));
@@ -0,0 +1,24 @@
+use advent_of_code_2020_challenges::{GoldChallenge, SilverChallenge};
+
+// Feeds arbitrary bytes to a day's constructor, then to both parts whenever the
+// input was accepted. Rejecting input with an `Err` is fine, only panics are found.
+pub fn fuzz_challenge<D, F>(parse_challenge: F, data: &[u8])
+where
+    D: SilverChallenge + GoldChallenge,
+    <D as SilverChallenge>::Answer: std::fmt::Debug,
+    <D as SilverChallenge>::Error: std::fmt::Debug,
+    <D as GoldChallenge>::Answer: std::fmt::Debug,
+    <D as GoldChallenge>::Error: std::fmt::Debug,
+    F: Fn(&str) -> Result<D, String>,
+{
+    // constructors only take text, so bytes that are not utf-8 can never reach them
+    let data = match std::str::from_utf8(data) {
+        Ok(data) => data,
+        Err(_) => return,
+    };
+
+    if let Ok(mut challenge) = parse_challenge(data) {
+        let _ = challenge.attempt_silver();
+        let _ = challenge.attempt_gold();
+    }
+}
@@ -0,0 +1,213 @@
+#[cfg(test)]
+mod tests;
+
+use advent_of_code_2020_challenges::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+// Answers and parsed structures are handed to python as native objects through
+// this conversion, so each type a day exposes needs an implementation here.
+trait IntoPython {
+    fn into_python(self, py: Python<'_>) -> PyResult<Py<PyAny>>;
+}
+
+impl IntoPython for () {
+    fn into_python(self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        Ok(py.None())
+    }
+}
+
+impl IntoPython for u32 {
+    fn into_python(self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        Ok(self.into_pyobject(py)?.into_any().unbind())
+    }
+}
+
+impl IntoPython for u64 {
+    fn into_python(self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        Ok(self.into_pyobject(py)?.into_any().unbind())
+    }
+}
+
+impl IntoPython for usize {
+    fn into_python(self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        Ok(self.into_pyobject(py)?.into_any().unbind())
+    }
+}
+
+impl<T: IntoPython + Clone> IntoPython for &[T] {
+    fn into_python(self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let items = self
+            .iter()
+            .map(|item| item.clone().into_python(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, items)?.into_any().unbind())
+    }
+}
+
+// a password with its policy, as a dict of `low`, `high`, `letter` and `password`
+impl IntoPython for PasswordEntry {
+    fn into_python(self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let entry = PyDict::new(py);
+        entry.set_item("low", self.low)?;
+        entry.set_item("high", self.high)?;
+        entry.set_item("letter", self.letter)?;
+        entry.set_item("password", self.password)?;
+        Ok(entry.into_any().unbind())
+    }
+}
+
+// a list of rows of the map as read, each a list with `True` where there is a tree
+impl IntoPython for &TreeMap {
+    fn into_python(self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let rows = (0..self.height())
+            .map(|row| {
+                let squares =
+                    (0..self.width()).map(|column| self.is_tree(column, row) == Some(true));
+                PyList::new(py, squares)
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, rows)?.into_any().unbind())
+    }
+}
+
+// a passport as a list of its fields in order, since keys can be repeated
+impl IntoPython for Passport {
+    fn into_python(self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        self.fields().into_python(py)
+    }
+}
+
+// a field as a dict of `key`, `value` and the `line` and `column` it starts at
+impl IntoPython for PassportField {
+    fn into_python(self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let field = PyDict::new(py);
+        field.set_item("key", self.key)?;
+        field.set_item("value", self.value)?;
+        field.set_item("line", self.location.line)?;
+        field.set_item("column", self.location.column)?;
+        Ok(field.into_any().unbind())
+    }
+}
+
+// a boarding pass as a dict of its `code` and the `row`, `column` and `seat_id` of its seat
+impl IntoPython for BoardingPass {
+    fn into_python(self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let pass = PyDict::new(py);
+        pass.set_item("code", self.encode())?;
+        pass.set_item("row", self.row())?;
+        pass.set_item("column", self.column())?;
+        pass.set_item("seat_id", self.seat_id())?;
+        Ok(pass.into_any().unbind())
+    }
+}
+
+fn into_python_result<A, E>(py: Python<'_>, result: Result<A, E>) -> PyResult<Py<PyAny>>
+where
+    A: IntoPython,
+    E: std::fmt::Debug,
+{
+    match result {
+        Ok(answer) => answer.into_python(py),
+        Err(error) => Err(PyValueError::new_err(format!("{:?}", error))),
+    }
+}
+
+// Wraps a day in a python class of the same name, taking the puzzle input in its
+// constructor. Any listed accessors are public methods of the day returning its
+// parsed structure, and are exposed as read only properties.
+macro_rules! python_day {
+    ($wrapper:ident, $day:ident, $name:literal $(, $accessor:ident)*) => {
+        #[pyclass(name = $name)]
+        struct $wrapper {
+            challenge: $day,
+        }
+
+        #[pymethods]
+        impl $wrapper {
+            #[new]
+            fn new(data: &str) -> PyResult<Self> {
+                let data = normalize_challenge_input::<$day>(data, false)
+                    .map_err(PyValueError::new_err)?;
+                let challenge = $day::new(&data).map_err(PyValueError::new_err)?;
+                Ok(Self { challenge })
+            }
+
+            fn silver(&mut self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+                into_python_result(py, self.challenge.attempt_silver())
+            }
+
+            fn gold(&mut self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+                into_python_result(py, self.challenge.attempt_gold())
+            }
+
+            $(
+                #[getter]
+                fn $accessor(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+                    self.challenge.$accessor().into_python(py)
+                }
+            )*
+
+            fn __repr__(&self) -> String {
+                format!("{:?}", self.challenge)
+            }
+        }
+    };
+}
+
+python_day!(PyDay01, Day01, "Day01", entries, target);
+python_day!(PyDay02, Day02, "Day02", entries);
+python_day!(PyDay03, Day03, "Day03", map);
+python_day!(PyDay04, Day04, "Day04", passports);
+python_day!(PyDay05, Day05, "Day05", passes);
+python_day!(PyDay06, Day06, "Day06");
+python_day!(PyDay07, Day07, "Day07");
+python_day!(PyDay08, Day08, "Day08");
+python_day!(PyDay09, Day09, "Day09");
+python_day!(PyDay10, Day10, "Day10");
+python_day!(PyDay11, Day11, "Day11");
+python_day!(PyDay12, Day12, "Day12");
+python_day!(PyDay13, Day13, "Day13");
+python_day!(PyDay14, Day14, "Day14");
+python_day!(PyDay15, Day15, "Day15");
+python_day!(PyDay16, Day16, "Day16");
+python_day!(PyDay17, Day17, "Day17");
+python_day!(PyDay18, Day18, "Day18");
+python_day!(PyDay19, Day19, "Day19");
+python_day!(PyDay20, Day20, "Day20");
+python_day!(PyDay21, Day21, "Day21");
+python_day!(PyDay22, Day22, "Day22");
+python_day!(PyDay23, Day23, "Day23");
+python_day!(PyDay24, Day24, "Day24");
+python_day!(PyDay25, Day25, "Day25");
+
+#[pymodule]
+fn advent_of_code_2020(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyDay01>()?;
+    module.add_class::<PyDay02>()?;
+    module.add_class::<PyDay03>()?;
+    module.add_class::<PyDay04>()?;
+    module.add_class::<PyDay05>()?;
+    module.add_class::<PyDay06>()?;
+    module.add_class::<PyDay07>()?;
+    module.add_class::<PyDay08>()?;
+    module.add_class::<PyDay09>()?;
+    module.add_class::<PyDay10>()?;
+    module.add_class::<PyDay11>()?;
+    module.add_class::<PyDay12>()?;
+    module.add_class::<PyDay13>()?;
+    module.add_class::<PyDay14>()?;
+    module.add_class::<PyDay15>()?;
+    module.add_class::<PyDay16>()?;
+    module.add_class::<PyDay17>()?;
+    module.add_class::<PyDay18>()?;
+    module.add_class::<PyDay19>()?;
+    module.add_class::<PyDay20>()?;
+    module.add_class::<PyDay21>()?;
+    module.add_class::<PyDay22>()?;
+    module.add_class::<PyDay23>()?;
+    module.add_class::<PyDay24>()?;
+    module.add_class::<PyDay25>()?;
+    Ok(())
+}
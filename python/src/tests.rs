@@ -0,0 +1,86 @@
+// import everything from the parent module file (in this case lib.rs)
+use super::*;
+use pyo3::types::PyDict;
+use std::ffi::CString;
+
+fn run_python(code: &str) -> PyResult<()> {
+    let code = CString::new(code).unwrap();
+
+    Python::initialize();
+    Python::attach(|py| {
+        let module = PyModule::new(py, "advent_of_code_2020")?;
+        advent_of_code_2020(&module)?;
+
+        // also importable by name, as the pytest cases do
+        py.import("sys")?
+            .getattr("modules")?
+            .set_item("advent_of_code_2020", &module)?;
+
+        let globals = PyDict::new(py);
+        globals.set_item("aoc", module)?;
+        py.run(&code, Some(&globals), None)
+    })
+}
+
+// the pytest cases, run without pytest by calling every `test_` function
+#[test]
+fn pytest_cases_pass() {
+    let cases = include_str!("../tests/test_days.py");
+    run_python(&format!(
+        "{}\nfor name, case in list(globals().items()):\n    if name.startswith('test_'):\n        case()\n",
+        cases
+    ))
+    .unwrap();
+}
+
+#[test]
+fn module_exposes_every_day() {
+    run_python("assert all(hasattr(aoc, f'Day{day:02}') for day in range(1, 26))").unwrap();
+}
+
+#[test]
+fn day_is_constructed_from_input() {
+    run_python("assert 'Day01' in repr(aoc.Day01('1721\\r\\n979\\r\\n'))").unwrap();
+}
+
+#[test]
+fn empty_input_raises_value_error() {
+    run_python(
+        "
+try:
+    aoc.Day01('\\n')
+    raise AssertionError('expected a ValueError')
+except ValueError:
+    pass
+",
+    )
+    .unwrap();
+}
+
+#[test]
+fn answers_and_accessors_are_native() {
+    run_python(
+        "
+day = aoc.Day01('1721\\n979\\n366\\n299\\n675\\n1456')
+assert day.silver() == 514579
+assert day.gold() == 241861950
+assert day.entries == [1721, 979, 366, 299, 675, 1456]
+assert day.target == 2020
+",
+    )
+    .unwrap();
+}
+
+#[test]
+fn failed_answer_raises_value_error() {
+    run_python(
+        "
+try:
+    aoc.Day01('1\\n2').silver()
+    raise AssertionError('expected a ValueError')
+except ValueError as error:
+    assert 'No 2 entries sum to 2020.' in str(error)
+",
+    )
+    .unwrap();
+}
@@ -0,0 +1,43 @@
+# Run with pytest after `maturin develop`, and by `cargo test` against the module
+# built into the test binary.
+import advent_of_code_2020 as aoc
+
+
+def test_day01_entries_and_target():
+    day = aoc.Day01("1721\n979\n366\n299\n675\n1456")
+    assert day.entries == [1721, 979, 366, 299, 675, 1456]
+    assert day.target == 2020
+    assert (day.silver(), day.gold()) == (514579, 241861950)
+
+
+def test_day02_password_policies():
+    day = aoc.Day02("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc")
+    assert day.entries[1] == {"low": 1, "high": 3, "letter": "b", "password": "cdefg"}
+    assert len(day.entries) == 3
+    assert (day.silver(), day.gold()) == (2, 1)
+
+
+def test_day03_tree_map():
+    day = aoc.Day03("..#\n#..\n.#.")
+    assert day.map == [
+        [False, False, True],
+        [True, False, False],
+        [False, True, False],
+    ]
+
+
+def test_day04_passports():
+    day = aoc.Day04("ecl:gry pid:860033327\nbyr:1937\n\niyr:2013 ecl:amb")
+    assert len(day.passports) == 2
+    assert day.passports[0][2] == {"key": "byr", "value": "1937", "line": 2, "column": 1}
+    assert [field["key"] for field in day.passports[1]] == ["iyr", "ecl"]
+    assert day.passports[1][1]["column"] == 10
+
+
+def test_day05_boarding_passes():
+    day = aoc.Day05("FBFBBFFRLR\nBBFFBBFRLL")
+    assert day.passes == [
+        {"code": "FBFBBFFRLR", "row": 44, "column": 5, "seat_id": 357},
+        {"code": "BBFFBBFRLL", "row": 102, "column": 4, "seat_id": 820},
+    ]
+    assert day.silver() == 820
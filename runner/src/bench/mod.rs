@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests;
+
+use crate::challenges::check_day_available;
+use advent_of_code_2020_challenges::*;
+use separator::Separatable;
+use std::time::{Duration, Instant};
+
+pub struct BenchOptions {
+    iterations: u32,
+    solve_silver: bool,
+    solve_gold: bool,
+}
+
+impl BenchOptions {
+    pub fn new(iterations: u32, solve_silver: bool, solve_gold: bool) -> Self {
+        BenchOptions {
+            iterations,
+            solve_silver,
+            solve_gold,
+        }
+    }
+}
+
+pub fn parse_iterations(iterations: &str) -> Result<u32, String> {
+    match iterations.trim().parse() {
+        Ok(iterations) => check_iterations(iterations),
+        Err(_) => Err(format!("Unrecognized iterations given: {}.", iterations)),
+    }
+}
+
+// also applied to iterations from config files and the environment
+pub fn check_iterations(iterations: u32) -> Result<u32, String> {
+    match iterations {
+        0 => Err("Iterations must be at least 1.".into()),
+        iterations => Ok(iterations),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timings {
+    pub mean: Duration,
+    pub best: Duration,
+}
+
+impl Timings {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let best = *samples.iter().min()?;
+        let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+        Some(Timings { mean, best })
+    }
+}
+
+fn print_timings(heading: &str, samples: &[Duration]) {
+    println!(" -> {}", heading);
+    if let Some(timings) = Timings::from_samples(samples) {
+        println!(
+            "    Mean time: {:>10} μs",
+            timings.mean.as_micros().separated_string()
+        );
+        println!(
+            "    Best time: {:>10} μs",
+            timings.best.as_micros().separated_string()
+        );
+    }
+}
+
+// each part is solved on a fresh clone of the parsed day, so that days caching
+// state between attempts are timed as if solving for the first time
+pub fn bench_challenges<D, F>(
+    parse_challenge: F,
+    data: &str,
+    options: &BenchOptions,
+) -> Result<(), String>
+where
+    D: SilverChallenge + GoldChallenge + Clone,
+    <D as SilverChallenge>::Answer: std::fmt::Debug,
+    <D as SilverChallenge>::Error: std::fmt::Debug,
+    <D as GoldChallenge>::Answer: std::fmt::Debug,
+    <D as GoldChallenge>::Error: std::fmt::Debug,
+    F: Fn(&str) -> Result<D, String>,
+{
+    let mut parse_samples = Vec::new();
+    let mut challenge = None;
+    for _ in 0..options.iterations {
+        let data_parse_start_time = Instant::now();
+        challenge = Some(parse_challenge(data)?);
+        parse_samples.push(data_parse_start_time.elapsed());
+    }
+    print_timings("Input data", &parse_samples);
+
+    let challenge = match challenge {
+        Some(challenge) => challenge,
+        None => return Ok(()),
+    };
+
+    if options.solve_silver {
+        let mut silver_samples = Vec::new();
+        for _ in 0..options.iterations {
+            let mut attempt = challenge.clone();
+            let silver_start_time = Instant::now();
+            let _ = attempt.attempt_silver();
+            silver_samples.push(silver_start_time.elapsed());
+        }
+        print_timings("Silver", &silver_samples);
+    }
+
+    if options.solve_gold {
+        let mut gold_samples = Vec::new();
+        for _ in 0..options.iterations {
+            let mut attempt = challenge.clone();
+            let gold_start_time = Instant::now();
+            let _ = attempt.attempt_gold();
+            gold_samples.push(gold_start_time.elapsed());
+        }
+        print_timings("Gold", &gold_samples);
+    }
+
+    Ok(())
+}
+
+pub fn bench_challenges_for_day(
+    day: &u32,
+    options: &BenchOptions,
+    data: String,
+) -> Result<(), String> {
+    check_day_available(day)?;
+    println!("==> Day {}", day);
+
+    let data_str = data.as_str();
+    match day {
+        #[cfg(feature = "day01")]
+        1 => bench_challenges(Day01::new, data_str, options)?,
+        #[cfg(feature = "day02")]
+        2 => bench_challenges(Day02::new, data_str, options)?,
+        #[cfg(feature = "day03")]
+        3 => bench_challenges(Day03::new, data_str, options)?,
+        #[cfg(feature = "day04")]
+        4 => bench_challenges(Day04::new, data_str, options)?,
+        #[cfg(feature = "day05")]
+        5 => bench_challenges(Day05::new, data_str, options)?,
+        #[cfg(feature = "day06")]
+        6 => bench_challenges(Day06::new, data_str, options)?,
+        #[cfg(feature = "day07")]
+        7 => bench_challenges(Day07::new, data_str, options)?,
+        #[cfg(feature = "day08")]
+        8 => bench_challenges(Day08::new, data_str, options)?,
+        #[cfg(feature = "day09")]
+        9 => bench_challenges(Day09::new, data_str, options)?,
+        #[cfg(feature = "day10")]
+        10 => bench_challenges(Day10::new, data_str, options)?,
+        #[cfg(feature = "day11")]
+        11 => bench_challenges(Day11::new, data_str, options)?,
+        #[cfg(feature = "day12")]
+        12 => bench_challenges(Day12::new, data_str, options)?,
+        #[cfg(feature = "day13")]
+        13 => bench_challenges(Day13::new, data_str, options)?,
+        #[cfg(feature = "day14")]
+        14 => bench_challenges(Day14::new, data_str, options)?,
+        #[cfg(feature = "day15")]
+        15 => bench_challenges(Day15::new, data_str, options)?,
+        #[cfg(feature = "day16")]
+        16 => bench_challenges(Day16::new, data_str, options)?,
+        #[cfg(feature = "day17")]
+        17 => bench_challenges(Day17::new, data_str, options)?,
+        #[cfg(feature = "day18")]
+        18 => bench_challenges(Day18::new, data_str, options)?,
+        #[cfg(feature = "day19")]
+        19 => bench_challenges(Day19::new, data_str, options)?,
+        #[cfg(feature = "day20")]
+        20 => bench_challenges(Day20::new, data_str, options)?,
+        #[cfg(feature = "day21")]
+        21 => bench_challenges(Day21::new, data_str, options)?,
+        #[cfg(feature = "day22")]
+        22 => bench_challenges(Day22::new, data_str, options)?,
+        #[cfg(feature = "day23")]
+        23 => bench_challenges(Day23::new, data_str, options)?,
+        #[cfg(feature = "day24")]
+        24 => bench_challenges(Day24::new, data_str, options)?,
+        #[cfg(feature = "day25")]
+        25 => bench_challenges(Day25::new, data_str, options)?,
+        _ => return Err(format!("Unrecognized date given: {}.", day)),
+    };
+
+    println!();
+    Ok(())
+}
@@ -0,0 +1,34 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+#[test]
+fn iterations_must_be_positive() {
+    assert_eq!(parse_iterations("25"), Ok(25));
+    assert_eq!(
+        parse_iterations("0"),
+        Err("Iterations must be at least 1.".into())
+    );
+    assert!(parse_iterations("many").is_err());
+}
+
+#[test]
+fn timings_have_mean_and_best() {
+    let samples = [
+        Duration::from_micros(30),
+        Duration::from_micros(10),
+        Duration::from_micros(20),
+    ];
+
+    assert_eq!(
+        Timings::from_samples(&samples),
+        Some(Timings {
+            mean: Duration::from_micros(20),
+            best: Duration::from_micros(10),
+        })
+    );
+}
+
+#[test]
+fn no_samples_have_no_timings() {
+    assert_eq!(Timings::from_samples(&[]), None);
+}
@@ -0,0 +1,201 @@
+#[cfg(test)]
+mod tests;
+
+use crate::default_input_file_path;
+use crate::verify::read_recorded_answers;
+use advent_of_code_2020_challenges::*;
+use std::any::TypeId;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PartStatus {
+    NotBuilt,
+    NotImplemented,
+    Implemented,
+}
+
+impl std::fmt::Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartStatus::NotBuilt => write!(f, "not built"),
+            PartStatus::NotImplemented => write!(f, "NYI"),
+            PartStatus::Implemented => write!(f, "implemented"),
+        }
+    }
+}
+
+// unsolved parts are stubs answering `Err("NYI")` with the unit type, until
+// they are implemented with an answer type of their own
+fn part_status<A: 'static>() -> PartStatus {
+    match TypeId::of::<A>() == TypeId::of::<()>() {
+        true => PartStatus::NotImplemented,
+        false => PartStatus::Implemented,
+    }
+}
+
+pub fn challenge_status<D>() -> (PartStatus, PartStatus)
+where
+    D: SilverChallenge + GoldChallenge,
+    <D as SilverChallenge>::Answer: 'static,
+    <D as GoldChallenge>::Answer: 'static,
+{
+    (
+        part_status::<<D as SilverChallenge>::Answer>(),
+        part_status::<<D as GoldChallenge>::Answer>(),
+    )
+}
+
+pub fn challenge_status_for_day(day: &u32) -> (PartStatus, PartStatus) {
+    match day {
+        #[cfg(feature = "day01")]
+        1 => challenge_status::<Day01>(),
+        #[cfg(feature = "day02")]
+        2 => challenge_status::<Day02>(),
+        #[cfg(feature = "day03")]
+        3 => challenge_status::<Day03>(),
+        #[cfg(feature = "day04")]
+        4 => challenge_status::<Day04>(),
+        #[cfg(feature = "day05")]
+        5 => challenge_status::<Day05>(),
+        #[cfg(feature = "day06")]
+        6 => challenge_status::<Day06>(),
+        #[cfg(feature = "day07")]
+        7 => challenge_status::<Day07>(),
+        #[cfg(feature = "day08")]
+        8 => challenge_status::<Day08>(),
+        #[cfg(feature = "day09")]
+        9 => challenge_status::<Day09>(),
+        #[cfg(feature = "day10")]
+        10 => challenge_status::<Day10>(),
+        #[cfg(feature = "day11")]
+        11 => challenge_status::<Day11>(),
+        #[cfg(feature = "day12")]
+        12 => challenge_status::<Day12>(),
+        #[cfg(feature = "day13")]
+        13 => challenge_status::<Day13>(),
+        #[cfg(feature = "day14")]
+        14 => challenge_status::<Day14>(),
+        #[cfg(feature = "day15")]
+        15 => challenge_status::<Day15>(),
+        #[cfg(feature = "day16")]
+        16 => challenge_status::<Day16>(),
+        #[cfg(feature = "day17")]
+        17 => challenge_status::<Day17>(),
+        #[cfg(feature = "day18")]
+        18 => challenge_status::<Day18>(),
+        #[cfg(feature = "day19")]
+        19 => challenge_status::<Day19>(),
+        #[cfg(feature = "day20")]
+        20 => challenge_status::<Day20>(),
+        #[cfg(feature = "day21")]
+        21 => challenge_status::<Day21>(),
+        #[cfg(feature = "day22")]
+        22 => challenge_status::<Day22>(),
+        #[cfg(feature = "day23")]
+        23 => challenge_status::<Day23>(),
+        #[cfg(feature = "day24")]
+        24 => challenge_status::<Day24>(),
+        #[cfg(feature = "day25")]
+        25 => challenge_status::<Day25>(),
+        _ => (PartStatus::NotBuilt, PartStatus::NotBuilt),
+    }
+}
+
+pub fn format_size(bytes: u64) -> String {
+    match bytes {
+        0..=1023 => format!("{} B", bytes),
+        _ => format!("{:.1} KiB", bytes as f64 / 1024.0),
+    }
+}
+
+pub fn describe_input(data: &str) -> String {
+    let size = format_size(data.len() as u64);
+    match data.lines().count() {
+        1 => format!("1 line, {}", size),
+        lines => format!("{} lines, {}", lines, size),
+    }
+}
+
+fn describe_recorded_answers(answers_dir: &Path, day: &u32) -> String {
+    let recorded_answers = match read_recorded_answers(answers_dir, day) {
+        Ok(Some(recorded_answers)) => recorded_answers,
+        Ok(None) => return "-".into(),
+        Err(error) => return error,
+    };
+
+    let parts = [
+        ("silver", recorded_answers.silver),
+        ("gold", recorded_answers.gold),
+    ];
+    let answers = parts
+        .iter()
+        .filter_map(|(label, answer)| {
+            answer
+                .as_ref()
+                .map(|answer| format!("{} {}", label, answer))
+        })
+        .collect::<Vec<_>>();
+    match answers.is_empty() {
+        true => "-".into(),
+        false => answers.join(", "),
+    }
+}
+
+// columns are padded to their widest cell, leaving the last column unpadded
+pub fn format_table(rows: &[Vec<String>]) -> String {
+    let column_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let widths = (0..column_count)
+        .map(|column| {
+            rows.iter()
+                .filter_map(|row| row.get(column))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = String::new();
+    for row in rows {
+        let mut line = String::new();
+        for (column, cell) in row.iter().enumerate() {
+            match column + 1 == row.len() {
+                true => line.push_str(cell),
+                false => line.push_str(&format!("{:<width$}  ", cell, width = widths[column])),
+            }
+        }
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+pub fn list_days(input_dir: &Path, answers_dir: &Path) -> Result<(), String> {
+    let mut rows = vec![vec![
+        "Day".to_string(),
+        "Title".to_string(),
+        "Input".to_string(),
+        "Silver".to_string(),
+        "Gold".to_string(),
+        "Recorded answers".to_string(),
+    ]];
+
+    for day in 1..=25 {
+        let input = match std::fs::read_to_string(default_input_file_path(input_dir, &day)) {
+            Ok(data) => describe_input(&data),
+            Err(_) => "missing".into(),
+        };
+        let (silver, gold) = challenge_status_for_day(&day);
+
+        rows.push(vec![
+            day.to_string(),
+            puzzle_title(day).unwrap_or("-").to_string(),
+            input,
+            silver.to_string(),
+            gold.to_string(),
+            describe_recorded_answers(answers_dir, &day),
+        ]);
+    }
+
+    print!("{}", format_table(&rows));
+    Ok(())
+}
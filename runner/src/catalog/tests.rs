@@ -0,0 +1,64 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+struct Stub;
+
+impl SilverChallenge for Stub {
+    type Answer = ();
+    type Error = &'static str;
+
+    fn attempt_silver(&mut self) -> Result<Self::Answer, Self::Error> {
+        Err("NYI")
+    }
+}
+
+impl GoldChallenge for Stub {
+    type Answer = u64;
+    type Error = &'static str;
+
+    fn attempt_gold(&mut self) -> Result<Self::Answer, Self::Error> {
+        Ok(2020)
+    }
+}
+
+#[test]
+fn unit_answers_are_not_implemented() {
+    assert_eq!(
+        challenge_status::<Stub>(),
+        (PartStatus::NotImplemented, PartStatus::Implemented)
+    );
+}
+
+#[test]
+fn unknown_days_are_not_built() {
+    assert_eq!(
+        challenge_status_for_day(&26),
+        (PartStatus::NotBuilt, PartStatus::NotBuilt)
+    );
+}
+
+#[test]
+fn input_is_described_by_lines_and_size() {
+    assert_eq!(describe_input("1721\n979\n366"), "3 lines, 12 B");
+    assert_eq!(describe_input("0,3,6"), "1 line, 5 B");
+    assert_eq!(format_size(1024), "1.0 KiB");
+    assert_eq!(format_size(10_342), "10.1 KiB");
+}
+
+#[test]
+fn table_columns_are_aligned() {
+    let rows = vec![
+        vec!["Day".to_string(), "Title".to_string(), "Input".to_string()],
+        vec![
+            "1".to_string(),
+            "Report Repair".to_string(),
+            "missing".to_string(),
+        ],
+        vec!["16".to_string(), "-".to_string(), "".to_string()],
+    ];
+
+    assert_eq!(
+        format_table(&rows),
+        "Day  Title          Input\n1    Report Repair  missing\n16   -\n"
+    );
+}
@@ -0,0 +1,360 @@
+#[cfg(test)]
+mod tests;
+
+use crate::reference::compare_with_reference;
+use crate::verify::verify_recorded_answers;
+use advent_of_code_2020_challenges::*;
+use separator::Separatable;
+use std::path::PathBuf;
+use std::time::Instant;
+
+pub struct ChallengeOptions {
+    show_timing: bool,
+    show_solutions: bool,
+    solve_silver: bool,
+    solve_gold: bool,
+    reference: Option<String>,
+    answers_dir: Option<PathBuf>,
+    target: Option<u64>,
+    verbose: bool,
+}
+
+// shows timings and solutions of both parts, without comparing answers to anything
+impl Default for ChallengeOptions {
+    fn default() -> Self {
+        ChallengeOptions {
+            show_timing: true,
+            show_solutions: true,
+            solve_silver: true,
+            solve_gold: true,
+            reference: None,
+            answers_dir: None,
+            target: None,
+            verbose: false,
+        }
+    }
+}
+
+impl ChallengeOptions {
+    pub fn with_timing(mut self, show_timing: bool) -> Self {
+        self.show_timing = show_timing;
+        self
+    }
+
+    pub fn with_solutions(mut self, show_solutions: bool) -> Self {
+        self.show_solutions = show_solutions;
+        self
+    }
+
+    pub fn with_silver(mut self, solve_silver: bool) -> Self {
+        self.solve_silver = solve_silver;
+        self
+    }
+
+    pub fn with_gold(mut self, solve_gold: bool) -> Self {
+        self.solve_gold = solve_gold;
+        self
+    }
+
+    // runs another solver on the same input and compares its answers
+    pub fn with_reference(mut self, reference: Option<String>) -> Self {
+        self.reference = reference;
+        self
+    }
+
+    // checks the answers against those recorded in the given answers directory
+    pub fn with_recorded_verification(mut self, answers_dir: Option<PathBuf>) -> Self {
+        self.answers_dir = answers_dir;
+        self
+    }
+
+    // only day 1 takes a target, any other day fails with one
+    pub fn with_target(mut self, target: Option<u64>) -> Self {
+        self.target = target;
+        self
+    }
+
+    // also prints the details behind the answers of days implementing `Report`
+    pub fn with_verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+}
+
+// debug representations of the answers of each part solved without an error
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ChallengeAnswers {
+    pub silver: Option<String>,
+    pub gold: Option<String>,
+}
+
+// unused when the only days compiled in implement `Report`
+#[allow(dead_code)]
+pub fn attempt_challenges<D, F>(
+    possible_challenge: F,
+    options: &ChallengeOptions,
+) -> Result<ChallengeAnswers, String>
+where
+    D: SilverChallenge + GoldChallenge + std::fmt::Debug,
+    <D as SilverChallenge>::Answer: std::fmt::Debug,
+    <D as SilverChallenge>::Error: std::fmt::Debug,
+    <D as GoldChallenge>::Answer: std::fmt::Debug,
+    <D as GoldChallenge>::Error: std::fmt::Debug,
+    F: Fn() -> Result<D, String> + Sized,
+{
+    attempt_challenges_with_report(possible_challenge, options, |_| Vec::new())
+}
+
+// only used by days implementing `Report`, which may not be compiled in
+#[cfg_attr(
+    not(any(
+        feature = "day02",
+        feature = "day03",
+        feature = "day04",
+        feature = "day05"
+    )),
+    allow(dead_code)
+)]
+pub fn attempt_reported_challenges<D, F>(
+    possible_challenge: F,
+    options: &ChallengeOptions,
+) -> Result<ChallengeAnswers, String>
+where
+    D: SilverChallenge + GoldChallenge + Report + std::fmt::Debug,
+    <D as SilverChallenge>::Answer: std::fmt::Debug,
+    <D as SilverChallenge>::Error: std::fmt::Debug,
+    <D as GoldChallenge>::Answer: std::fmt::Debug,
+    <D as GoldChallenge>::Error: std::fmt::Debug,
+    F: Fn() -> Result<D, String> + Sized,
+{
+    attempt_challenges_with_report(possible_challenge, options, D::report)
+}
+
+fn attempt_challenges_with_report<D, F, R>(
+    possible_challenge: F,
+    options: &ChallengeOptions,
+    report: R,
+) -> Result<ChallengeAnswers, String>
+where
+    D: SilverChallenge + GoldChallenge + std::fmt::Debug,
+    <D as SilverChallenge>::Answer: std::fmt::Debug,
+    <D as SilverChallenge>::Error: std::fmt::Debug,
+    <D as GoldChallenge>::Answer: std::fmt::Debug,
+    <D as GoldChallenge>::Error: std::fmt::Debug,
+    F: Fn() -> Result<D, String> + Sized,
+    R: Fn(&D) -> Vec<String>,
+{
+    println!(" -> Input data");
+
+    let data_parse_start_time = Instant::now();
+    let mut challenge = possible_challenge()?;
+    let mut answers = ChallengeAnswers::default();
+    let data_parse_total_time = data_parse_start_time.elapsed();
+
+    if options.show_timing {
+        println!(
+            "    Processing time: {:>10} μs",
+            data_parse_total_time.as_micros().separated_string()
+        );
+    }
+
+    if options.solve_silver {
+        println!(" -> Silver");
+
+        let silver_start_time = Instant::now();
+        let silver_solution = challenge.attempt_silver();
+        let silver_total_time = silver_start_time.elapsed();
+        if options.show_timing {
+            println!(
+                "    Processing time: {:>10} μs",
+                silver_total_time.as_micros().separated_string(),
+            );
+        }
+        if options.show_solutions {
+            println!("    Result: {:?}", silver_solution);
+        }
+        answers.silver = silver_solution.ok().map(|answer| format!("{:?}", answer));
+    }
+
+    if options.solve_gold {
+        println!(" -> Gold");
+
+        let gold_start_time = Instant::now();
+        let gold_solution = challenge.attempt_gold();
+        let gold_total_time = gold_start_time.elapsed();
+        if options.show_timing {
+            println!(
+                "    Processing time: {:>10} μs",
+                gold_total_time.as_micros().separated_string(),
+            );
+        }
+        if options.show_solutions {
+            println!("    Result: {:?}", gold_solution);
+        }
+        answers.gold = gold_solution.ok().map(|answer| format!("{:?}", answer));
+    }
+
+    let report_lines = match options.verbose {
+        true => report(&challenge),
+        false => Vec::new(),
+    };
+    if !report_lines.is_empty() {
+        println!(" -> Report");
+        for line in report_lines {
+            println!("    {}", line);
+        }
+    }
+
+    Ok(answers)
+}
+
+// only day 1 searches for a target, which is 2020 unless another is given
+#[cfg(feature = "day01")]
+fn day01_with_target(data: &str, target: Option<u64>) -> Result<Day01, String> {
+    let challenge = Day01::new(data)?;
+    Ok(match target {
+        Some(target) => challenge.with_target(target),
+        None => challenge,
+    })
+}
+
+// Whether the day was compiled into the runner through its `dayNN` feature, read from
+// the runner's own features like the arms dispatching to each day. The challenges
+// crate may have more days enabled by other members of the workspace.
+pub fn is_day_available(day: u32) -> bool {
+    const AVAILABLE_DAYS: [bool; 25] = [
+        cfg!(feature = "day01"),
+        cfg!(feature = "day02"),
+        cfg!(feature = "day03"),
+        cfg!(feature = "day04"),
+        cfg!(feature = "day05"),
+        cfg!(feature = "day06"),
+        cfg!(feature = "day07"),
+        cfg!(feature = "day08"),
+        cfg!(feature = "day09"),
+        cfg!(feature = "day10"),
+        cfg!(feature = "day11"),
+        cfg!(feature = "day12"),
+        cfg!(feature = "day13"),
+        cfg!(feature = "day14"),
+        cfg!(feature = "day15"),
+        cfg!(feature = "day16"),
+        cfg!(feature = "day17"),
+        cfg!(feature = "day18"),
+        cfg!(feature = "day19"),
+        cfg!(feature = "day20"),
+        cfg!(feature = "day21"),
+        cfg!(feature = "day22"),
+        cfg!(feature = "day23"),
+        cfg!(feature = "day24"),
+        cfg!(feature = "day25"),
+    ];
+
+    (1..=25).contains(&day) && AVAILABLE_DAYS[day as usize - 1]
+}
+
+// days can be left out of a build by disabling their `dayNN` cargo feature
+pub fn check_day_available(day: &u32) -> Result<(), String> {
+    match day {
+        1..=25 if !is_day_available(*day) => Err(format!(
+            "Day {} is not available in this build, enable the `day{:02}` feature.",
+            day, day
+        )),
+        1..=25 => Ok(()),
+        _ => Err(format!("Unrecognized date given: {}.", day)),
+    }
+}
+
+pub fn attempt_challenges_for_day(
+    day: &u32,
+    options: &ChallengeOptions,
+    data: String,
+) -> Result<ChallengeAnswers, String> {
+    check_day_available(day)?;
+    if options.target.is_some() && *day != 1 {
+        return Err(format!("Day {} does not take a target.", day));
+    }
+    println!("==> Day {}", day);
+
+    let data_str = data.as_str();
+    let answers = match day {
+        // Day constructors are in a closure to defer instantiation for timing input parsing
+        #[cfg(feature = "day01")]
+        1 => attempt_challenges(|| day01_with_target(data_str, options.target), options)?,
+        #[cfg(feature = "day02")]
+        2 => attempt_reported_challenges(|| Day02::new(data_str), options)?,
+        #[cfg(feature = "day03")]
+        3 => attempt_reported_challenges(|| Day03::new(data_str), options)?,
+        #[cfg(feature = "day04")]
+        4 => attempt_reported_challenges(|| Day04::new(data_str), options)?,
+        #[cfg(feature = "day05")]
+        5 => attempt_reported_challenges(|| Day05::new(data_str), options)?,
+        #[cfg(feature = "day06")]
+        6 => attempt_challenges(|| Day06::new(data_str), options)?,
+        #[cfg(feature = "day07")]
+        7 => attempt_challenges(|| Day07::new(data_str), options)?,
+        #[cfg(feature = "day08")]
+        8 => attempt_challenges(|| Day08::new(data_str), options)?,
+        #[cfg(feature = "day09")]
+        9 => attempt_challenges(|| Day09::new(data_str), options)?,
+        #[cfg(feature = "day10")]
+        10 => attempt_challenges(|| Day10::new(data_str), options)?,
+        #[cfg(feature = "day11")]
+        11 => attempt_challenges(|| Day11::new(data_str), options)?,
+        #[cfg(feature = "day12")]
+        12 => attempt_challenges(|| Day12::new(data_str), options)?,
+        #[cfg(feature = "day13")]
+        13 => attempt_challenges(|| Day13::new(data_str), options)?,
+        #[cfg(feature = "day14")]
+        14 => attempt_challenges(|| Day14::new(data_str), options)?,
+        #[cfg(feature = "day15")]
+        15 => attempt_challenges(|| Day15::new(data_str), options)?,
+        #[cfg(feature = "day16")]
+        16 => attempt_challenges(|| Day16::new(data_str), options)?,
+        #[cfg(feature = "day17")]
+        17 => attempt_challenges(|| Day17::new(data_str), options)?,
+        #[cfg(feature = "day18")]
+        18 => attempt_challenges(|| Day18::new(data_str), options)?,
+        #[cfg(feature = "day19")]
+        19 => attempt_challenges(|| Day19::new(data_str), options)?,
+        #[cfg(feature = "day20")]
+        20 => attempt_challenges(|| Day20::new(data_str), options)?,
+        #[cfg(feature = "day21")]
+        21 => attempt_challenges(|| Day21::new(data_str), options)?,
+        #[cfg(feature = "day22")]
+        22 => attempt_challenges(|| Day22::new(data_str), options)?,
+        #[cfg(feature = "day23")]
+        23 => attempt_challenges(|| Day23::new(data_str), options)?,
+        #[cfg(feature = "day24")]
+        24 => attempt_challenges(|| Day24::new(data_str), options)?,
+        #[cfg(feature = "day25")]
+        25 => attempt_challenges(|| Day25::new(data_str), options)?,
+        _ => return Err(format!("Unrecognized date given: {}.", day)),
+    };
+
+    // the blank line closing the day is printed before any mismatch is returned
+    let reference_result = match &options.reference {
+        Some(reference) => compare_with_reference(
+            reference,
+            day,
+            data_str,
+            &answers,
+            options.solve_silver,
+            options.solve_gold,
+        ),
+        None => Ok(()),
+    };
+    let recorded_result = match &options.answers_dir {
+        Some(answers_dir) => verify_recorded_answers(
+            answers_dir,
+            day,
+            &answers,
+            options.solve_silver,
+            options.solve_gold,
+        ),
+        None => Ok(()),
+    };
+
+    println!();
+    reference_result.and(recorded_result).map(|_| answers)
+}
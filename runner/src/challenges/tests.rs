@@ -0,0 +1,17 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+// holds for any set of day features, e.g. `--no-default-features --features day07`
+#[test]
+fn available_days_are_dispatched() {
+    for day in 1..=25 {
+        let result = attempt_challenges_for_day(&day, &ChallengeOptions::default(), "1".into());
+        match check_day_available(&day) {
+            Ok(()) => assert_ne!(
+                result.err(),
+                Some(format!("Unrecognized date given: {}.", day))
+            ),
+            Err(error) => assert_eq!(result.err(), Some(error)),
+        }
+    }
+}
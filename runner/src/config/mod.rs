@@ -0,0 +1,262 @@
+#[cfg(test)]
+mod tests;
+
+use crate::bench::check_iterations;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+pub const CONFIG_FILE_NAME: &str = "aoc.toml";
+
+// Settings shared by the runner's commands, where each layer of configuration
+// (command line, environment, config files) leaves unset whatever it does not
+// specify, so that the layer below it applies instead. Only settings the runner
+// has options for are configurable, it has no output formats, time budgets or
+// threads to choose between.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub input_dir: Option<PathBuf>,
+    pub answers_dir: Option<PathBuf>,
+    pub hide_timing: Option<bool>,
+    pub hide_solutions: Option<bool>,
+    pub preserve_whitespace: Option<bool>,
+    pub reference: Option<String>,
+    pub iterations: Option<u32>,
+    pub seed: Option<u64>,
+}
+
+// the effective value of every setting, after all layers were applied
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    pub input_dir: PathBuf,
+    pub answers_dir: PathBuf,
+    pub hide_timing: bool,
+    pub hide_solutions: bool,
+    pub preserve_whitespace: bool,
+    pub reference: Option<String>,
+    pub iterations: u32,
+    pub seed: u64,
+}
+
+impl Config {
+    pub fn defaults() -> Self {
+        Config {
+            input_dir: Some(PathBuf::from("input")),
+            answers_dir: Some(PathBuf::from("answers")),
+            hide_timing: Some(false),
+            hide_solutions: Some(false),
+            preserve_whitespace: Some(false),
+            reference: None,
+            iterations: Some(10),
+            seed: Some(2020),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let config: Config =
+            toml::from_str(contents).map_err(|error| error.message().to_string())?;
+        if let Some(iterations) = config.iterations {
+            check_iterations(iterations)?;
+        }
+        Ok(config)
+    }
+
+    pub fn from_file(file_path: &Path) -> Result<Option<Self>, String> {
+        if !file_path.is_file() {
+            return Ok(None);
+        }
+
+        std::fs::read_to_string(file_path)
+            .map_err(|error| error.to_string())
+            .and_then(|contents| Config::parse(&contents))
+            .map(Some)
+            .map_err(|error| format!("Unable to use {}: {}", file_path.display(), error))
+    }
+
+    // settings given as `AOC_` environment variables, such as `AOC_HIDE_TIMING=true`
+    pub fn from_env<F>(variable: F) -> Result<Self, String>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        fn parse_variable<T: std::str::FromStr>(
+            name: &str,
+            value: Option<String>,
+        ) -> Result<Option<T>, String> {
+            parse_checked_variable(name, value, Ok)
+        }
+
+        // values failing the same checks as on the command line are invalid too
+        fn parse_checked_variable<T: std::str::FromStr, C>(
+            name: &str,
+            value: Option<String>,
+            check: C,
+        ) -> Result<Option<T>, String>
+        where
+            C: Fn(T) -> Result<T, String>,
+        {
+            match value {
+                Some(value) => match value.trim().parse().ok().map(&check) {
+                    Some(Ok(value)) => Ok(Some(value)),
+                    _ => Err(format!("Invalid value for {}: {}", name, value)),
+                },
+                None => Ok(None),
+            }
+        }
+
+        Ok(Config {
+            input_dir: variable("AOC_INPUT_DIR").map(PathBuf::from),
+            answers_dir: variable("AOC_ANSWERS_DIR").map(PathBuf::from),
+            hide_timing: parse_variable("AOC_HIDE_TIMING", variable("AOC_HIDE_TIMING"))?,
+            hide_solutions: parse_variable("AOC_HIDE_SOLUTIONS", variable("AOC_HIDE_SOLUTIONS"))?,
+            preserve_whitespace: parse_variable(
+                "AOC_PRESERVE_WHITESPACE",
+                variable("AOC_PRESERVE_WHITESPACE"),
+            )?,
+            reference: variable("AOC_REFERENCE"),
+            iterations: parse_checked_variable(
+                "AOC_ITERATIONS",
+                variable("AOC_ITERATIONS"),
+                check_iterations,
+            )?,
+            seed: parse_checked_variable("AOC_SEED", variable("AOC_SEED"), check_seed)?,
+        })
+    }
+
+    // settings from this layer take precedence over those of the layer below it
+    pub fn or(self, below: Config) -> Self {
+        Config {
+            input_dir: self.input_dir.or(below.input_dir),
+            answers_dir: self.answers_dir.or(below.answers_dir),
+            hide_timing: self.hide_timing.or(below.hide_timing),
+            hide_solutions: self.hide_solutions.or(below.hide_solutions),
+            preserve_whitespace: self.preserve_whitespace.or(below.preserve_whitespace),
+            reference: self.reference.or(below.reference),
+            iterations: self.iterations.or(below.iterations),
+            seed: self.seed.or(below.seed),
+        }
+    }
+
+    pub fn settings(self) -> Settings {
+        let config = self.or(Config::defaults());
+        Settings {
+            input_dir: config.input_dir.unwrap_or_default(),
+            answers_dir: config.answers_dir.unwrap_or_default(),
+            hide_timing: config.hide_timing.unwrap_or_default(),
+            hide_solutions: config.hide_solutions.unwrap_or_default(),
+            preserve_whitespace: config.preserve_whitespace.unwrap_or_default(),
+            reference: config.reference,
+            iterations: config.iterations.unwrap_or_default(),
+            seed: config.seed.unwrap_or_default(),
+        }
+    }
+
+    // every setting by its name in the config file, with its value written as toml
+    pub fn entries(&self) -> Vec<(&'static str, Option<String>)> {
+        fn toml_value<T: Clone + Into<toml::Value>>(value: &Option<T>) -> Option<String> {
+            value.clone().map(|value| value.into().to_string())
+        }
+
+        vec![
+            (
+                "input_dir",
+                toml_value(&self.input_dir.as_ref().map(|dir| dir.display().to_string())),
+            ),
+            (
+                "answers_dir",
+                toml_value(
+                    &self
+                        .answers_dir
+                        .as_ref()
+                        .map(|dir| dir.display().to_string()),
+                ),
+            ),
+            ("hide_timing", toml_value(&self.hide_timing)),
+            ("hide_solutions", toml_value(&self.hide_solutions)),
+            ("preserve_whitespace", toml_value(&self.preserve_whitespace)),
+            ("reference", toml_value(&self.reference)),
+            ("iterations", toml_value(&self.iterations.map(i64::from))),
+            // seeds are checked to fit a toml integer, so are written as they are
+            ("seed", self.seed.map(|seed| seed.to_string())),
+        ]
+    }
+}
+
+// toml integers are signed, so larger seeds could not be kept in a config file
+pub fn check_seed(seed: u64) -> Result<u64, String> {
+    match seed <= i64::MAX as u64 {
+        true => Ok(seed),
+        false => Err(format!("Seed must be at most {}.", i64::MAX)),
+    }
+}
+
+pub fn parse_seed(seed: &str) -> Result<u64, String> {
+    match seed.trim().parse() {
+        Ok(seed) => check_seed(seed),
+        Err(_) => Err(format!("Unrecognized seed given: {}.", seed)),
+    }
+}
+
+// config files in order of precedence, the workspace's before the user's
+pub fn config_file_paths() -> Vec<PathBuf> {
+    let mut file_paths = vec![PathBuf::from(CONFIG_FILE_NAME)];
+
+    let user_config_dir = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")),
+    };
+    if let Some(user_config_dir) = user_config_dir {
+        file_paths.push(user_config_dir.join(CONFIG_FILE_NAME));
+    }
+
+    file_paths
+}
+
+// Every layer of configuration below the command line, labelled by where it came
+// from and in order of precedence, ending with the defaults.
+pub fn load_config_layers() -> Result<Vec<(String, Config)>, String> {
+    let mut layers = vec![(
+        "environment".to_string(),
+        Config::from_env(|name| std::env::var(name).ok())?,
+    )];
+
+    for file_path in config_file_paths() {
+        if let Some(config) = Config::from_file(&file_path)? {
+            layers.push((file_path.display().to_string(), config));
+        }
+    }
+
+    layers.push(("default".to_string(), Config::defaults()));
+    Ok(layers)
+}
+
+pub fn resolve_settings(command_line: Config, layers: &[(String, Config)]) -> Settings {
+    layers
+        .iter()
+        .fold(command_line, |config, (_, layer)| config.or(layer.clone()))
+        .settings()
+}
+
+// each setting with its effective value and the layer it was taken from
+pub fn format_config(layers: &[(String, Config)]) -> String {
+    let layer_entries = layers
+        .iter()
+        .map(|(source, config)| (source, config.entries()))
+        .collect::<Vec<_>>();
+
+    let mut lines = Vec::new();
+    for (index, (name, _)) in Config::defaults().entries().iter().enumerate() {
+        let effective = layer_entries
+            .iter()
+            .find_map(|(source, entries)| entries[index].1.as_ref().map(|value| (value, source)));
+        match effective {
+            Some((value, source)) => lines.push((format!("{} = {}", name, value), source.as_str())),
+            None => lines.push((format!("# {} is not set", name), "default")),
+        }
+    }
+
+    let width = lines.iter().map(|(line, _)| line.len()).max().unwrap_or(0);
+    lines
+        .iter()
+        .map(|(line, source)| format!("{:<width$}  # {}\n", line, source, width = width))
+        .collect()
+}
@@ -0,0 +1,151 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+use std::collections::HashMap;
+
+fn environment(variables: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let variables = variables
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect::<HashMap<_, _>>();
+    move |name| variables.get(name).cloned()
+}
+
+#[test]
+fn config_file_is_parsed() {
+    let config = Config::parse("input_dir = \"puzzles\"\nhide_timing = true\niterations = 50\n");
+
+    assert_eq!(
+        config,
+        Ok(Config {
+            input_dir: Some(PathBuf::from("puzzles")),
+            hide_timing: Some(true),
+            iterations: Some(50),
+            ..Config::default()
+        })
+    );
+}
+
+#[test]
+fn unknown_settings_fail() {
+    let error = Config::parse("hide_timings = true").unwrap_err();
+
+    assert!(error.contains("unknown field `hide_timings`"), "{}", error);
+}
+
+#[test]
+fn environment_is_parsed() {
+    let config = Config::from_env(environment(&[
+        ("AOC_HIDE_SOLUTIONS", "true"),
+        ("AOC_SEED", "42"),
+        ("AOC_REFERENCE", "python3 reference.py"),
+        ("AOC_ANSWERS_DIR", "/srv/aoc/answers"),
+    ]));
+
+    assert_eq!(
+        config,
+        Ok(Config {
+            answers_dir: Some(PathBuf::from("/srv/aoc/answers")),
+            hide_solutions: Some(true),
+            reference: Some("python3 reference.py".into()),
+            seed: Some(42),
+            ..Config::default()
+        })
+    );
+}
+
+#[test]
+fn invalid_environment_fails() {
+    assert_eq!(
+        Config::from_env(environment(&[("AOC_ITERATIONS", "many")])),
+        Err("Invalid value for AOC_ITERATIONS: many".into())
+    );
+}
+
+#[test]
+fn layers_apply_in_order_of_precedence() {
+    let command_line = Config {
+        iterations: Some(3),
+        ..Config::default()
+    };
+    let layers = vec![
+        (
+            "environment".to_string(),
+            Config {
+                iterations: Some(5),
+                seed: Some(7),
+                ..Config::default()
+            },
+        ),
+        (
+            "aoc.toml".to_string(),
+            Config {
+                seed: Some(11),
+                hide_timing: Some(true),
+                ..Config::default()
+            },
+        ),
+    ];
+
+    let settings = resolve_settings(command_line, &layers);
+    assert_eq!(settings.iterations, 3);
+    assert_eq!(settings.seed, 7);
+    assert!(settings.hide_timing);
+    assert_eq!(settings.input_dir, PathBuf::from("input"));
+}
+
+#[test]
+fn config_shows_source_of_each_setting() {
+    let layers = vec![
+        (
+            "aoc.toml".to_string(),
+            Config {
+                hide_timing: Some(true),
+                ..Config::default()
+            },
+        ),
+        ("default".to_string(), Config::defaults()),
+    ];
+
+    assert_eq!(
+        format_config(&layers),
+        concat!(
+            "input_dir = \"input\"          # default\n",
+            "answers_dir = \"answers\"      # default\n",
+            "hide_timing = true           # aoc.toml\n",
+            "hide_solutions = false       # default\n",
+            "preserve_whitespace = false  # default\n",
+            "# reference is not set       # default\n",
+            "iterations = 10              # default\n",
+            "seed = 2020                  # default\n",
+        )
+    );
+}
+
+#[test]
+fn config_values_are_checked_like_flags() {
+    assert_eq!(
+        Config::parse("iterations = 0"),
+        Err("Iterations must be at least 1.".into())
+    );
+    assert_eq!(
+        Config::from_env(environment(&[("AOC_ITERATIONS", "0")])),
+        Err("Invalid value for AOC_ITERATIONS: 0".into())
+    );
+    assert_eq!(
+        Config::from_env(environment(&[("AOC_SEED", "18446744073709551615")])),
+        Err("Invalid value for AOC_SEED: 18446744073709551615".into())
+    );
+}
+
+#[test]
+fn seeds_must_fit_a_config_file() {
+    assert_eq!(parse_seed("9223372036854775807"), Ok(i64::MAX as u64));
+    assert_eq!(
+        parse_seed("9223372036854775808"),
+        Err("Seed must be at most 9223372036854775807.".into())
+    );
+    assert_eq!(
+        parse_seed("many"),
+        Err("Unrecognized seed given: many.".into())
+    );
+}
@@ -0,0 +1,203 @@
+#[cfg(test)]
+mod tests;
+
+use crate::challenges::check_day_available;
+use advent_of_code_2020_challenges::*;
+use std::collections::HashSet;
+
+// Statistics that apply to any input regardless of day: line and group counts,
+// grid dimensions when every line is the same width, the range of any numbers,
+// and the distinct tokens and characters found.
+pub fn input_statistics(data: &str) -> Vec<(&'static str, String)> {
+    let lines = data.lines().collect::<Vec<_>>();
+    let groups = data
+        .split("\n\n")
+        .filter(|group| !group.trim().is_empty())
+        .count();
+    let mut statistics = vec![
+        ("Lines", lines.len().to_string()),
+        ("Groups", groups.to_string()),
+    ];
+
+    let width = lines.first().map(|line| line.chars().count()).unwrap_or(0);
+    let is_grid = lines.len() > 1
+        && width > 1
+        && lines
+            .iter()
+            .all(|line| line.chars().count() == width && !line.contains(' '));
+    if is_grid {
+        statistics.push(("Grid", format!("{} wide by {} high", width, lines.len())));
+    }
+
+    let numbers = numbers_in(data);
+    if let (Some(minimum), Some(maximum)) = (numbers.iter().min(), numbers.iter().max()) {
+        statistics.push((
+            "Values",
+            format!("{} numbers from {} to {}", numbers.len(), minimum, maximum),
+        ));
+    }
+
+    let tokens = data.split_whitespace().collect::<HashSet<_>>();
+    statistics.push(("Distinct tokens", tokens.len().to_string()));
+
+    let mut characters = data
+        .chars()
+        .filter(|character| !character.is_whitespace())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    characters.sort_unstable();
+    statistics.push((
+        "Distinct characters",
+        match characters.len() {
+            // only small alphabets such as the symbols of a grid are worth listing
+            0..=12 => format!(
+                "{} ({})",
+                characters.len(),
+                characters.into_iter().collect::<String>()
+            ),
+            count => count.to_string(),
+        },
+    ));
+
+    statistics
+}
+
+// signed integers anywhere in the input, such as `-4` in `jmp -4`
+fn numbers_in(data: &str) -> Vec<i64> {
+    let mut numbers = Vec::new();
+    let mut current = String::new();
+
+    for character in data.chars().chain(std::iter::once(' ')) {
+        let starts_negative = character == '-' && current.is_empty();
+        if character.is_ascii_digit() || starts_negative {
+            current.push(character);
+            continue;
+        }
+        if let Ok(number) = current.parse() {
+            numbers.push(number);
+        }
+        current.clear();
+    }
+
+    numbers
+}
+
+fn print_statistics(heading: &str, statistics: &[(&'static str, String)]) {
+    println!(" -> {}", heading);
+    let label_width = statistics
+        .iter()
+        .map(|(label, _)| label.len() + 1)
+        .max()
+        .unwrap_or(0);
+    for (label, value) in statistics {
+        println!(
+            "    {:<width$} {}",
+            format!("{}:", label),
+            value,
+            width = label_width
+        );
+    }
+}
+
+// only used by days implementing `Inspect`, which may not be compiled in
+#[cfg_attr(
+    not(any(
+        feature = "day01",
+        feature = "day02",
+        feature = "day03",
+        feature = "day04",
+        feature = "day05",
+        feature = "day07"
+    )),
+    allow(dead_code)
+)]
+pub fn inspect_challenge<D, F>(parse_challenge: F, data: &str) -> Result<(), String>
+where
+    D: Inspect,
+    F: Fn(&str) -> Result<D, String>,
+{
+    let challenge = parse_challenge(data)?;
+    print_statistics("Parsed structure", &challenge.inspect());
+    Ok(())
+}
+
+// The parser of a day without `Inspect` is still run to validate the input. Unused
+// when the only days compiled in implement `Inspect`.
+#[allow(dead_code)]
+fn parse_challenge<D, F>(parse_challenge: F, data: &str) -> Result<(), String>
+where
+    F: Fn(&str) -> Result<D, String>,
+{
+    parse_challenge(data)?;
+    println!(" -> Parsed structure");
+    println!("    Not described, as the day does not implement `Inspect`");
+    Ok(())
+}
+
+pub fn inspect_challenges_for_day(day: &u32, data: String) -> Result<(), String> {
+    check_day_available(day)?;
+    println!("==> Day {}", day);
+
+    print_statistics("Input data", &input_statistics(&data));
+
+    // every day's parser is run to validate the input, but only days implementing
+    // `Inspect` describe the structure it was parsed into
+    let data_str = data.as_str();
+    match day {
+        #[cfg(feature = "day01")]
+        1 => inspect_challenge(Day01::new, data_str)?,
+        #[cfg(feature = "day02")]
+        2 => inspect_challenge(Day02::new, data_str)?,
+        #[cfg(feature = "day03")]
+        3 => inspect_challenge(Day03::new, data_str)?,
+        #[cfg(feature = "day04")]
+        4 => inspect_challenge(Day04::new, data_str)?,
+        #[cfg(feature = "day05")]
+        5 => inspect_challenge(Day05::new, data_str)?,
+        #[cfg(feature = "day06")]
+        6 => parse_challenge(Day06::new, data_str)?,
+        #[cfg(feature = "day07")]
+        7 => inspect_challenge(Day07::new, data_str)?,
+        #[cfg(feature = "day08")]
+        8 => parse_challenge(Day08::new, data_str)?,
+        #[cfg(feature = "day09")]
+        9 => parse_challenge(Day09::new, data_str)?,
+        #[cfg(feature = "day10")]
+        10 => parse_challenge(Day10::new, data_str)?,
+        #[cfg(feature = "day11")]
+        11 => parse_challenge(Day11::new, data_str)?,
+        #[cfg(feature = "day12")]
+        12 => parse_challenge(Day12::new, data_str)?,
+        #[cfg(feature = "day13")]
+        13 => parse_challenge(Day13::new, data_str)?,
+        #[cfg(feature = "day14")]
+        14 => parse_challenge(Day14::new, data_str)?,
+        #[cfg(feature = "day15")]
+        15 => parse_challenge(Day15::new, data_str)?,
+        #[cfg(feature = "day16")]
+        16 => parse_challenge(Day16::new, data_str)?,
+        #[cfg(feature = "day17")]
+        17 => parse_challenge(Day17::new, data_str)?,
+        #[cfg(feature = "day18")]
+        18 => parse_challenge(Day18::new, data_str)?,
+        #[cfg(feature = "day19")]
+        19 => parse_challenge(Day19::new, data_str)?,
+        #[cfg(feature = "day20")]
+        20 => parse_challenge(Day20::new, data_str)?,
+        #[cfg(feature = "day21")]
+        21 => parse_challenge(Day21::new, data_str)?,
+        #[cfg(feature = "day22")]
+        22 => parse_challenge(Day22::new, data_str)?,
+        #[cfg(feature = "day23")]
+        23 => parse_challenge(Day23::new, data_str)?,
+        #[cfg(feature = "day24")]
+        24 => parse_challenge(Day24::new, data_str)?,
+        #[cfg(feature = "day25")]
+        25 => parse_challenge(Day25::new, data_str)?,
+        _ => return Err(format!("Unrecognized date given: {}.", day)),
+    };
+
+    println!();
+    Ok(())
+}
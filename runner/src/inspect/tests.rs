@@ -0,0 +1,56 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+fn statistic(statistics: &[(&'static str, String)], label: &str) -> Option<String> {
+    statistics
+        .iter()
+        .find(|(statistic_label, _)| *statistic_label == label)
+        .map(|(_, value)| value.clone())
+}
+
+#[test]
+fn numbers_are_counted_with_their_range() {
+    let statistics = input_statistics("1721\n979\n366\n299\n675\n1456");
+
+    assert_eq!(statistic(&statistics, "Lines"), Some("6".into()));
+    assert_eq!(statistic(&statistics, "Groups"), Some("1".into()));
+    assert_eq!(
+        statistic(&statistics, "Values"),
+        Some("6 numbers from 299 to 1721".into())
+    );
+}
+
+#[test]
+fn negative_numbers_are_recognized() {
+    let statistics = input_statistics("nop +0\nacc +1\njmp -4");
+
+    assert_eq!(
+        statistic(&statistics, "Values"),
+        Some("3 numbers from -4 to 1".into())
+    );
+}
+
+#[test]
+fn grids_have_dimensions() {
+    let statistics = input_statistics("..##.\n#...#\n.#...");
+
+    assert_eq!(
+        statistic(&statistics, "Grid"),
+        Some("5 wide by 3 high".into())
+    );
+    assert_eq!(statistic(&statistics, "Values"), None);
+    assert_eq!(
+        statistic(&statistics, "Distinct characters"),
+        Some("2 (#.)".into())
+    );
+}
+
+#[test]
+fn groups_are_separated_by_blank_lines() {
+    let statistics = input_statistics("abc\n\na\nb\nc\n\nab\nac");
+
+    assert_eq!(statistic(&statistics, "Lines"), Some("8".into()));
+    assert_eq!(statistic(&statistics, "Groups"), Some("3".into()));
+    assert_eq!(statistic(&statistics, "Grid"), None);
+    assert_eq!(statistic(&statistics, "Distinct tokens"), Some("6".into()));
+}
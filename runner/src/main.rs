@@ -1,13 +1,93 @@
+mod bench;
+mod catalog;
 mod challenges;
-use challenges::{attempt_challenges_for_day, ChallengeOptions};
+mod config;
+mod inspect;
+mod new_day;
+mod reference;
+mod render;
+mod scaling;
+mod verify;
+use advent_of_code_2020_challenges::normalize_day_input;
+use bench::{bench_challenges_for_day, parse_iterations, BenchOptions};
+use catalog::list_days;
+use challenges::{
+    attempt_challenges_for_day, check_day_available, is_day_available, ChallengeAnswers,
+    ChallengeOptions,
+};
+use config::{format_config, load_config_layers, parse_seed, resolve_settings, Config, Settings};
+use inspect::inspect_challenges_for_day;
+use new_day::scaffold_day;
+use render::{parse_scale, render_challenge_for_day, render_output, RenderOptions};
+use scaling::{has_input_generator, parse_scale_size, scale_challenges_for_day, ScaleOptions};
 use std::fs::File;
 use std::io::prelude::*;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use structopt::StructOpt;
+use verify::record_answers;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "Advent of Code 2020")]
 struct ApplicationOptions {
+    // without a subcommand, the flags given are those of `run`
+    #[structopt(flatten)]
+    run: RunCommand,
+
+    /// Deprecated, use `bench --scale` instead
+    #[structopt(long, hidden = true, use_delimiter = true, parse(try_from_str = parse_scale_size))]
+    scale: Vec<usize>,
+
+    /// Deprecated, use `bench --seed` instead
+    #[structopt(long, hidden = true, parse(try_from_str = parse_seed))]
+    seed: Option<u64>,
+
+    /// Directory of input files named by day, such as `day01.txt` [default: input]
+    #[structopt(long, global = true, parse(from_os_str))]
+    input_dir: Option<PathBuf>,
+
+    /// Directory of answers recorded by `verify --record`, named by day [default: answers]
+    #[structopt(long, global = true, parse(from_os_str))]
+    answers_dir: Option<PathBuf>,
+
+    #[structopt(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Solve challenges, the default when no subcommand is given
+    Run(RunCommand),
+
+    /// Time parsing and solving over many iterations, or over generated inputs
+    Bench(BenchCommand),
+
+    /// Solve challenges and check their answers against those recorded
+    Verify(VerifyCommand),
+
+    /// Create the files for a day which do not exist yet
+    NewDay(NewDayCommand),
+
+    /// Parse input and report statistics on its structure without solving
+    Inspect(InspectCommand),
+
+    /// Draw the map of a day with how it was traversed, such as the path down day 3
+    Render(RenderCommand),
+
+    /// List every day with its title, input, implementation status and recorded answers
+    List,
+
+    /// Show the configuration read from `aoc.toml` files and the environment
+    Config(ConfigCommand),
+}
+
+#[derive(Debug, StructOpt)]
+enum ConfigCommand {
+    /// Print the effective value of each setting and where it was set
+    Show,
+}
+
+#[derive(Debug, StructOpt)]
+struct RunCommand {
     /// Which day of the challenge to run, runs all by default
     #[structopt(long, env = "CHALLENGE_DAY")]
     day: Option<u32>,
@@ -21,13 +101,21 @@ struct ApplicationOptions {
     data: Option<String>,
 
     /// Hide solutions
-    #[structopt(long)]
+    #[structopt(long, overrides_with = "no-hide-solutions")]
     hide_solutions: bool,
 
+    /// Show solutions, even when hidden by configuration
+    #[structopt(long, overrides_with = "hide-solutions")]
+    no_hide_solutions: bool,
+
     /// Hide time tracking
-    #[structopt(long)]
+    #[structopt(long, overrides_with = "no-hide-timing")]
     hide_timing: bool,
 
+    /// Show time tracking, even when hidden by configuration
+    #[structopt(long, overrides_with = "hide-timing")]
+    no_hide_timing: bool,
+
     /// Solve silver challenges, will solve both by default
     #[structopt(short = "s", long)]
     solve_silver: bool,
@@ -35,23 +123,246 @@ struct ApplicationOptions {
     /// Solve gold challenges, will solve both by default
     #[structopt(short = "g", long)]
     solve_gold: bool,
+
+    /// Reference solver command to compare answers with, run through `sh`, where any `{day}`
+    /// or `{input}` are replaced by the day and a path to its input, also given on stdin
+    #[structopt(long)]
+    reference: Option<String>,
+
+    #[structopt(flatten)]
+    whitespace: WhitespaceOptions,
+
+    /// Sum to search for instead of 2020, for day 1
+    #[structopt(long)]
+    target: Option<u64>,
+
+    /// Print details behind the answers, for days with a report
+    #[structopt(short, long)]
+    verbose: bool,
+}
+
+// whether to keep trailing whitespace of input data, shared by every command reading it
+#[derive(Debug, Clone, StructOpt)]
+struct WhitespaceOptions {
+    /// Keep trailing whitespace in the input data of every day, not only days needing it
+    #[structopt(long, overrides_with = "no-preserve-whitespace")]
+    preserve_whitespace: bool,
+
+    /// Trim trailing whitespace kept by configuration, except for days needing it
+    #[structopt(long, overrides_with = "preserve-whitespace")]
+    no_preserve_whitespace: bool,
+}
+
+#[derive(Debug, StructOpt)]
+struct BenchCommand {
+    /// Which day of the challenge to time, times all by default
+    #[structopt(long, env = "CHALLENGE_DAY")]
+    day: Option<u32>,
+
+    /// How many times to parse and solve each part [default: 10]
+    #[structopt(long, parse(try_from_str = parse_iterations))]
+    iterations: Option<u32>,
+
+    /// Time silver challenges, will time both by default
+    #[structopt(short = "s", long)]
+    solve_silver: bool,
+
+    /// Time gold challenges, will time both by default
+    #[structopt(short = "g", long)]
+    solve_gold: bool,
+
+    #[structopt(flatten)]
+    whitespace: WhitespaceOptions,
+
+    /// Time generated inputs of each size and estimate growth, e.g. `--scale 1k,10k,100k`
+    #[structopt(long, use_delimiter = true, parse(try_from_str = parse_scale_size))]
+    scale: Vec<usize>,
+
+    /// Seed used to generate inputs when scaling [default: 2020]
+    #[structopt(long, parse(try_from_str = parse_seed))]
+    seed: Option<u64>,
+}
+
+#[derive(Debug, StructOpt)]
+struct VerifyCommand {
+    /// Which day of the challenge to verify, verifies all by default
+    #[structopt(long, env = "CHALLENGE_DAY")]
+    day: Option<u32>,
+
+    /// Record the current answers instead of checking them
+    #[structopt(long)]
+    record: bool,
+
+    #[structopt(flatten)]
+    whitespace: WhitespaceOptions,
+}
+
+#[derive(Debug, StructOpt)]
+struct NewDayCommand {
+    /// Which day of the challenge to create
+    day: u32,
+
+    /// Title of the day's puzzle, used in the heading of its README
+    #[structopt(long)]
+    title: Option<String>,
+
+    /// Puzzle input file to copy into the input directory
+    #[structopt(short, long, parse(from_os_str))]
+    input: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+struct InspectCommand {
+    /// Which day of the challenge to inspect, inspects all by default
+    #[structopt(long, env = "CHALLENGE_DAY")]
+    day: Option<u32>,
+
+    /// Input data file, defaults to input stored with date of challenge
+    #[structopt(short, long, env = "INPUT_FILE", parse(from_os_str))]
+    file: Option<PathBuf>,
+
+    /// Input string, defaults to input stored with date of challenge
+    #[structopt(short, long, env = "INPUT_DATA")]
+    data: Option<String>,
+
+    #[structopt(flatten)]
+    whitespace: WhitespaceOptions,
+}
+
+#[derive(Debug, StructOpt)]
+struct RenderCommand {
+    /// Which day of the challenge to render, the day 3 map or the day 5 seats
+    #[structopt(long, env = "CHALLENGE_DAY")]
+    day: u32,
+
+    /// Input data file, defaults to input stored with date of challenge
+    #[structopt(short, long, env = "INPUT_FILE", parse(from_os_str))]
+    file: Option<PathBuf>,
+
+    /// Input string, defaults to input stored with date of challenge
+    #[structopt(short, long, env = "INPUT_DATA")]
+    data: Option<String>,
+
+    /// Squares moved right with each step down the day 3 map
+    #[structopt(long, default_value = "3")]
+    right: usize,
+
+    /// Squares moved down with each step down the day 3 map
+    #[structopt(long, default_value = "1")]
+    down: usize,
+
+    /// File to write to instead of printing, as a PPM image when named `*.ppm` or as text
+    #[structopt(short, long, parse(from_os_str))]
+    output: Option<PathBuf>,
+
+    /// Pixels drawn for each square of an image
+    #[structopt(long, default_value = "4", parse(try_from_str = parse_scale))]
+    scale: usize,
+
+    #[structopt(flatten)]
+    whitespace: WhitespaceOptions,
 }
 
-fn build_challenge_options_for_day(options: &ApplicationOptions) -> ChallengeOptions {
-    let (solve_silver, solve_gold) = match (options.solve_silver, options.solve_gold) {
+fn parts_to_solve(solve_silver: bool, solve_gold: bool) -> (bool, bool) {
+    match (solve_silver, solve_gold) {
         // if none specified, run both
         (false, false) => (true, true),
         // otherwise solve whichever are specified
         (silver, gold) => (silver, gold),
-    };
-    ChallengeOptions::new(
-        !options.hide_timing,
-        !options.hide_solutions,
+    }
+}
+
+// a flag turns a setting on and its `--no-` counterpart turns it off, whichever
+// was given last, while giving neither leaves the setting to the config
+fn flag_setting(flag: bool, no_flag: bool) -> Option<bool> {
+    match (flag, no_flag) {
+        (true, _) => Some(true),
+        (_, true) => Some(false),
+        _ => None,
+    }
+}
+
+impl WhitespaceOptions {
+    fn setting(&self) -> Option<bool> {
+        flag_setting(self.preserve_whitespace, self.no_preserve_whitespace)
+    }
+}
+
+fn build_challenge_options_for_day(options: &RunCommand, settings: &Settings) -> ChallengeOptions {
+    let (solve_silver, solve_gold) = parts_to_solve(options.solve_silver, options.solve_gold);
+    ChallengeOptions::default()
+        .with_timing(!settings.hide_timing)
+        .with_solutions(!settings.hide_solutions)
+        .with_silver(solve_silver)
+        .with_gold(solve_gold)
+        .with_reference(settings.reference.clone())
+        .with_target(options.target)
+        .with_verbose(options.verbose)
+}
+
+fn build_bench_options(options: &BenchCommand, settings: &Settings) -> BenchOptions {
+    let (solve_silver, solve_gold) = parts_to_solve(options.solve_silver, options.solve_gold);
+    BenchOptions::new(settings.iterations, solve_silver, solve_gold)
+}
+
+fn build_scale_options(options: &BenchCommand, settings: &Settings) -> ScaleOptions {
+    let (solve_silver, solve_gold) = parts_to_solve(options.solve_silver, options.solve_gold);
+    ScaleOptions::new(
+        options.scale.clone(),
+        settings.seed,
         solve_silver,
         solve_gold,
     )
 }
 
+// the settings given on the command line for each command, over any config layers
+fn command_line_config(options: &ApplicationOptions) -> Config {
+    let config = Config {
+        input_dir: options.input_dir.clone(),
+        answers_dir: options.answers_dir.clone(),
+        ..Config::default()
+    };
+
+    match &options.command {
+        None | Some(Command::Run(_)) => {
+            let run_options = match &options.command {
+                Some(Command::Run(run_options)) => run_options,
+                _ => &options.run,
+            };
+            Config {
+                hide_timing: flag_setting(run_options.hide_timing, run_options.no_hide_timing),
+                hide_solutions: flag_setting(
+                    run_options.hide_solutions,
+                    run_options.no_hide_solutions,
+                ),
+                preserve_whitespace: run_options.whitespace.setting(),
+                reference: run_options.reference.clone(),
+                seed: options.seed,
+                ..config
+            }
+        }
+        Some(Command::Bench(bench_options)) => Config {
+            preserve_whitespace: bench_options.whitespace.setting(),
+            iterations: bench_options.iterations,
+            seed: bench_options.seed,
+            ..config
+        },
+        Some(Command::Verify(verify_options)) => Config {
+            preserve_whitespace: verify_options.whitespace.setting(),
+            ..config
+        },
+        Some(Command::Inspect(inspect_options)) => Config {
+            preserve_whitespace: inspect_options.whitespace.setting(),
+            ..config
+        },
+        Some(Command::Render(render_options)) => Config {
+            preserve_whitespace: render_options.whitespace.setting(),
+            ..config
+        },
+        Some(Command::NewDay(_)) | Some(Command::List) | Some(Command::Config(_)) => config,
+    }
+}
+
 fn read_file(file_path: PathBuf) -> std::io::Result<String> {
     let mut file = File::open(file_path)?;
     let mut contents = String::new();
@@ -61,65 +372,271 @@ fn read_file(file_path: PathBuf) -> std::io::Result<String> {
     Ok(contents)
 }
 
-// gather data for test between overridden data or input text file
+fn default_input_file_path(input_dir: &Path, day: &u32) -> PathBuf {
+    input_dir.join(format!("day{:02}.txt", day))
+}
+
+// days compiled into this build with an input file, in order
+fn days_with_input(input_dir: &Path) -> impl Iterator<Item = u32> + '_ {
+    (1..=25)
+        .filter(|day| is_day_available(*day))
+        .filter(move |day| default_input_file_path(input_dir, day).is_file())
+}
+
+// gather data for test between overridden data or input text file,
+// normalizing line endings and whitespace before any day parses it, where
+// whitespace is kept for days declaring it meaningful regardless of settings
 fn prepare_challenge_data_for_day(
     day: &u32,
     possible_data_override: &Option<String>,
+    settings: &Settings,
 ) -> Result<String, String> {
-    match possible_data_override {
-        Some(data) => Ok(data.clone()),
-        None => {
-            let mut file_path = PathBuf::new();
-            file_path.push("input");
-            file_path.push(format!("day{:02}.txt", day));
-
-            match read_file(file_path) {
-                Err(_) => Err(format!("Unable to read input file for day {}.", day)),
-                Ok(file_data) => Ok(file_data),
-            }
-        }
-    }
-}
+    let data = match possible_data_override {
+        Some(data) => data.clone(),
+        None => match read_file(default_input_file_path(&settings.input_dir, day)) {
+            Err(_) => return Err(format!("Unable to read input file for day {}.", day)),
+            Ok(file_data) => file_data,
+        },
+    };
 
-fn main() -> Result<(), String> {
-    let options = ApplicationOptions::from_args();
-    let challenge_options = build_challenge_options_for_day(&options);
+    normalize_day_input(*day, &data, settings.preserve_whitespace)
+        .map_err(|error| format!("Unable to use input for day {}: {}", day, error))
+}
 
-    // conditions for whether to use input data or a file, or default file(s)
-    let possible_data_override: Option<String> = match (options.day, &options.data, &options.file) {
+// conditions for whether to use input data or a file, or default file(s)
+fn read_data_override(
+    day: &Option<u32>,
+    data: &Option<String>,
+    file: &Option<PathBuf>,
+) -> Result<Option<String>, String> {
+    match (day, data, file) {
         (None, Some(_), _) | (None, _, Some(_)) => {
-            return Err("Must specify date when providing data or file.".into())
+            Err("Must specify date when providing data or file.".into())
         }
 
-        (_, Some(_), Some(_)) => return Err("Cannot specify both data and file.".into()),
+        (_, Some(_), Some(_)) => Err("Cannot specify both data and file.".into()),
 
         (Some(_), None, Some(file_path)) => match read_file(file_path.clone()) {
-            Err(_) => return Err("Unable to read input file.".into()),
-            Ok(file_data) => Some(file_data),
+            Err(_) => Err("Unable to read input file.".into()),
+            Ok(file_data) => Ok(Some(file_data)),
         },
 
-        (Some(_), Some(data), None) => Some(data.clone()),
+        (Some(_), Some(data), None) => Ok(Some(data.clone())),
 
         // no overrides specified for data or file
-        (_, None, None) => None,
-    };
+        (_, None, None) => Ok(None),
+    }
+}
+
+fn run(options: &RunCommand, settings: &Settings) -> Result<(), String> {
+    if options.target.is_some() && options.day.is_none() {
+        return Err("Must specify date when providing a target.".into());
+    }
+
+    let challenge_options = build_challenge_options_for_day(options, settings);
+    let possible_data_override = read_data_override(&options.day, &options.data, &options.file)?;
 
     // when a specific day is specified, only that days challenges will run
     // and if there is no input for that day, the application will terminate
     if let Some(day) = &options.day {
-        let data = prepare_challenge_data_for_day(day, &possible_data_override).unwrap();
-        return attempt_challenges_for_day(day, &challenge_options, data);
+        check_day_available(day)?;
+        let data = prepare_challenge_data_for_day(day, &possible_data_override, settings)?;
+        return attempt_challenges_for_day(day, &challenge_options, data).map(|_| ());
+    }
+
+    // when running through all days, will ignore days where no input exists or
+    // that were not compiled in, and will not end the application when a challenge fails
+    for day in days_with_input(&settings.input_dir) {
+        let result = prepare_challenge_data_for_day(&day, &possible_data_override, settings)
+            .and_then(|data| attempt_challenges_for_day(&day, &challenge_options, data));
+
+        if let Err(error) = result {
+            eprintln!("Failed to attempt challenge for day {}: {}", day, error);
+        }
+    }
+
+    Ok(())
+}
+
+fn bench(options: &BenchCommand, settings: &Settings) -> Result<(), String> {
+    // scaling runs on generated inputs instead of the input files
+    if !options.scale.is_empty() {
+        let scale_options = build_scale_options(options, settings);
+        if let Some(day) = &options.day {
+            return scale_challenges_for_day(day, &scale_options);
+        }
+
+        // when running through all days, will skip days without an input generator
+        for day in (1..=25).filter(has_input_generator) {
+            if let Err(error) = scale_challenges_for_day(&day, &scale_options) {
+                eprintln!("Failed to scale challenge for day {}: {}", day, error);
+            }
+        }
+
+        return Ok(());
+    }
+
+    let bench_options = build_bench_options(options, settings);
+    if let Some(day) = &options.day {
+        check_day_available(day)?;
+        let data = prepare_challenge_data_for_day(day, &None, settings)?;
+        return bench_challenges_for_day(day, &bench_options, data);
     }
 
-    // when running through all days, will ignore days where no input exists
-    // and will not end the application when a challenge fails
-    for day in 1..=25 {
-        if let Ok(data) = prepare_challenge_data_for_day(&day, &possible_data_override) {
-            if let Err(error) = attempt_challenges_for_day(&day, &challenge_options, data) {
-                eprintln!("Failed to attempt challenge for day {}: {}", day, error);
+    for day in days_with_input(&settings.input_dir) {
+        let result = prepare_challenge_data_for_day(&day, &None, settings)
+            .and_then(|data| bench_challenges_for_day(&day, &bench_options, data));
+
+        if let Err(error) = result {
+            eprintln!("Failed to time challenge for day {}: {}", day, error);
+        }
+    }
+
+    Ok(())
+}
+
+fn verify_day(day: &u32, options: &VerifyCommand, settings: &Settings) -> Result<(), String> {
+    let challenge_options = ChallengeOptions::default()
+        .with_timing(false)
+        .with_recorded_verification(match options.record {
+            true => None,
+            false => Some(settings.answers_dir.clone()),
+        });
+    let data = prepare_challenge_data_for_day(day, &None, settings)?;
+    let answers = attempt_challenges_for_day(day, &challenge_options, data)?;
+
+    if options.record {
+        match answers == ChallengeAnswers::default() {
+            true => println!("No answers to record for day {}", day),
+            false => {
+                let file_path = record_answers(&settings.answers_dir, day, &answers)?;
+                println!(
+                    "Recorded answers for day {} in {}",
+                    day,
+                    file_path.display()
+                );
             }
         }
+        println!();
+    }
+
+    Ok(())
+}
+
+fn verify(options: &VerifyCommand, settings: &Settings) -> Result<(), String> {
+    if let Some(day) = &options.day {
+        check_day_available(day)?;
+        return verify_day(day, options, settings);
+    }
+
+    // unlike running, any day failing verification fails the whole application
+    let mut failed_days = 0;
+    for day in days_with_input(&settings.input_dir) {
+        if let Err(error) = verify_day(&day, options, settings) {
+            eprintln!("Failed to verify challenge for day {}: {}", day, error);
+            failed_days += 1;
+        }
+    }
+
+    match failed_days {
+        0 => Ok(()),
+        _ => Err(format!("Failed to verify {} day(s).", failed_days)),
+    }
+}
+
+fn new_day(options: &NewDayCommand, settings: &Settings) -> Result<(), String> {
+    let input = match &options.input {
+        Some(file_path) => match read_file(file_path.clone()) {
+            Err(_) => return Err("Unable to read input file.".into()),
+            Ok(file_data) => Some(file_data),
+        },
+        None => None,
+    };
+
+    scaffold_day(
+        Path::new("."),
+        &settings.input_dir,
+        &options.day,
+        &options.title,
+        &input,
+    )
+}
+
+fn inspect(options: &InspectCommand, settings: &Settings) -> Result<(), String> {
+    let possible_data_override = read_data_override(&options.day, &options.data, &options.file)?;
+
+    if let Some(day) = &options.day {
+        check_day_available(day)?;
+        let data = prepare_challenge_data_for_day(day, &possible_data_override, settings)?;
+        return inspect_challenges_for_day(day, data);
+    }
+
+    // when inspecting all days, reports days whose input fails to parse and carries on
+    for day in days_with_input(&settings.input_dir) {
+        let result = prepare_challenge_data_for_day(&day, &None, settings)
+            .and_then(|data| inspect_challenges_for_day(&day, data));
+
+        if let Err(error) = result {
+            eprintln!("Failed to inspect challenge for day {}: {}", day, error);
+        }
     }
 
     Ok(())
 }
+
+fn render(options: &RenderCommand, settings: &Settings) -> Result<(), String> {
+    let day = Some(options.day);
+    let possible_data_override = read_data_override(&day, &options.data, &options.file)?;
+
+    check_day_available(&options.day)?;
+    let data = prepare_challenge_data_for_day(&options.day, &possible_data_override, settings)?;
+    let render_options = RenderOptions::new(
+        options.right,
+        options.down,
+        render_output(&options.output),
+        options.scale,
+    );
+    render_challenge_for_day(&options.day, data, &render_options)
+}
+
+fn main() -> Result<(), String> {
+    let options = ApplicationOptions::from_args();
+    let config_layers = load_config_layers()?;
+    let settings = resolve_settings(command_line_config(&options), &config_layers);
+
+    match &options.command {
+        Some(Command::Run(run_options)) => run(run_options, &settings),
+        Some(Command::Bench(bench_options)) => bench(bench_options, &settings),
+        Some(Command::Verify(verify_options)) => verify(verify_options, &settings),
+        Some(Command::NewDay(new_day_options)) => new_day(new_day_options, &settings),
+        Some(Command::Inspect(inspect_options)) => inspect(inspect_options, &settings),
+        Some(Command::Render(render_options)) => render(render_options, &settings),
+        Some(Command::List) => list_days(&settings.input_dir, &settings.answers_dir),
+        Some(Command::Config(ConfigCommand::Show)) => {
+            let mut layers = vec![("command line".to_string(), command_line_config(&options))];
+            layers.extend(config_layers);
+            print!("{}", format_config(&layers));
+            Ok(())
+        }
+
+        // scaling used to be a flag of the default action, and is still accepted as one
+        None if !options.scale.is_empty() => {
+            if options.run.data.is_some() || options.run.file.is_some() {
+                return Err("Cannot specify data or file when scaling.".into());
+            }
+
+            let bench_options = BenchCommand {
+                day: options.run.day,
+                iterations: None,
+                solve_silver: options.run.solve_silver,
+                solve_gold: options.run.solve_gold,
+                whitespace: options.run.whitespace.clone(),
+                scale: options.scale.clone(),
+                seed: options.seed,
+            };
+            bench(&bench_options, &settings)
+        }
+
+        None => run(&options.run, &settings),
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests;
+
+use std::path::{Path, PathBuf};
+
+// the stub every day starts from, answering both parts with not yet implemented
+pub fn module_template(day: &u32) -> String {
+    format!(
+        r#"#[cfg(test)]
+mod tests;
+
+use crate::{{ChallengeInput, GoldChallenge, SilverChallenge}};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Day{day:02} {{
+    data: String,
+}}
+
+impl Day{day:02} {{
+    pub fn new(data: &str) -> Result<Self, String> {{
+        Ok(Self {{ data: data.into() }})
+    }}
+}}
+
+impl ChallengeInput for Day{day:02} {{}}
+
+impl SilverChallenge for Day{day:02} {{
+    type Answer = ();
+    type Error = &'static str;
+
+    fn attempt_silver(&mut self) -> Result<Self::Answer, Self::Error>
+    where
+        Self::Answer: std::fmt::Debug,
+    {{
+        Err("NYI")
+    }}
+}}
+
+impl GoldChallenge for Day{day:02} {{
+    type Answer = ();
+    type Error = &'static str;
+
+    fn attempt_gold(&mut self) -> Result<Self::Answer, Self::Error>
+    where
+        Self::Answer: std::fmt::Debug,
+    {{
+        Err("NYI")
+    }}
+}}
+"#,
+        day = day
+    )
+}
+
+pub fn tests_template(day: &u32) -> String {
+    format!(
+        r#"// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+const SAMPLE_DATA: &str = "<replace this with sample data from challenge>";
+
+// replace the unit values `()` with the correct results based on the sample data,
+// more samples can be added and either part left out where a sample only covers one
+sample_tests!(Day{day:02}, [
+    {{ data: SAMPLE_DATA, silver: Ok(()), gold: Ok(()) }},
+]);
+"#,
+        day = day
+    )
+}
+
+pub fn readme_template(day: &u32, title: &Option<String>) -> String {
+    let heading = match title {
+        Some(title) => format!("# Day {}: {}", day, title),
+        None => format!("# Day {}", day),
+    };
+    format!(
+        "{}\n\nOriginal source available at [Advent of Code](https://adventofcode.com/2020/day/{})\n\n## Silver Challenge\n\n## Gold Challenge\n",
+        heading, day
+    )
+}
+
+// files making up a day along with their contents, relative to the workspace root
+pub fn day_files(day: &u32, title: &Option<String>) -> Vec<(PathBuf, String)> {
+    let mut module_path = PathBuf::new();
+    module_path.push("challenges");
+    module_path.push("src");
+    module_path.push(format!("day{:02}", day));
+
+    vec![
+        (module_path.join("mod.rs"), module_template(day)),
+        (module_path.join("tests.rs"), tests_template(day)),
+        (module_path.join("README.md"), readme_template(day, title)),
+    ]
+}
+
+fn write_new_file(file_path: &Path, contents: &str) -> Result<bool, String> {
+    if file_path.exists() {
+        return Ok(false);
+    }
+    if let Some(directory) = file_path.parent() {
+        std::fs::create_dir_all(directory)
+            .map_err(|error| format!("Unable to create {}: {}", directory.display(), error))?;
+    }
+    std::fs::write(file_path, contents)
+        .map_err(|error| format!("Unable to write {}: {}", file_path.display(), error))?;
+    Ok(true)
+}
+
+// Creates whichever of a day's files are missing, never overwriting existing ones,
+// along with its input file when puzzle input is given.
+pub fn scaffold_day(
+    root: &Path,
+    input_dir: &Path,
+    day: &u32,
+    title: &Option<String>,
+    input: &Option<String>,
+) -> Result<(), String> {
+    if !(1..=25).contains(day) {
+        return Err(format!("Unrecognized date given: {}.", day));
+    }
+    println!("==> Day {}", day);
+
+    let mut files = day_files(day, title);
+    if let Some(input) = input {
+        let input_path = input_dir.join(format!("day{:02}.txt", day));
+        files.push((input_path, input.clone()));
+    }
+
+    for (file_path, contents) in files {
+        match write_new_file(&root.join(&file_path), &contents)? {
+            true => println!(" -> Created {}", file_path.display()),
+            false => println!(" -> Kept existing {}", file_path.display()),
+        }
+    }
+
+    println!();
+    Ok(())
+}
@@ -0,0 +1,103 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+fn temporary_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!(
+        "advent-of-code-2020-new-day-{}-{}",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&root);
+    root
+}
+
+#[test]
+fn templates_are_named_by_day() {
+    assert!(module_template(&9).contains("pub struct Day09 {"));
+    assert!(tests_template(&9).contains("sample_tests!(Day09, ["));
+}
+
+#[test]
+fn readme_has_title_when_given() {
+    let readme = readme_template(&9, &Some("Encoding Error".into()));
+
+    assert!(readme.starts_with("# Day 9: Encoding Error\n"));
+    assert!(readme.contains("https://adventofcode.com/2020/day/9)"));
+    assert!(readme_template(&9, &None).starts_with("# Day 9\n"));
+}
+
+#[test]
+fn missing_files_are_created() {
+    let root = temporary_root("created");
+    scaffold_day(
+        &root,
+        Path::new("input"),
+        &12,
+        &None,
+        &Some("F10\nN3".into()),
+    )
+    .unwrap();
+
+    let module_path = root.join("challenges").join("src").join("day12");
+    assert_eq!(
+        std::fs::read_to_string(module_path.join("mod.rs")).unwrap(),
+        module_template(&12)
+    );
+    assert!(module_path.join("tests.rs").is_file());
+    assert!(module_path.join("README.md").is_file());
+    assert_eq!(
+        std::fs::read_to_string(root.join("input").join("day12.txt")).unwrap(),
+        "F10\nN3"
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn existing_files_are_kept() {
+    let root = temporary_root("kept");
+    let readme_path = root
+        .join("challenges")
+        .join("src")
+        .join("day03")
+        .join("README.md");
+    std::fs::create_dir_all(readme_path.parent().unwrap()).unwrap();
+    std::fs::write(&readme_path, "# Day 3: Toboggan Trajectory\n").unwrap();
+
+    scaffold_day(&root, Path::new("input"), &3, &None, &None).unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(&readme_path).unwrap(),
+        "# Day 3: Toboggan Trajectory\n"
+    );
+    assert!(!root.join("input").exists());
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn unrecognized_day_fails() {
+    assert_eq!(
+        scaffold_day(
+            &temporary_root("unrecognized"),
+            Path::new("input"),
+            &26,
+            &None,
+            &None
+        ),
+        Err("Unrecognized date given: 26.".into())
+    );
+}
+
+#[test]
+fn module_template_matches_unsolved_days() {
+    let unsolved_day = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../challenges/src/day25/mod.rs"
+    );
+
+    assert_eq!(
+        std::fs::read_to_string(unsolved_day).unwrap(),
+        module_template(&25)
+    );
+}
@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod tests;
+
+use crate::challenges::ChallengeAnswers;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Part {
+    Silver,
+    Gold,
+}
+
+// Runs the reference command for a day through `sh` and returns what it printed, so
+// that quoted arguments and paths with spaces work as they would in a terminal. Any
+// `{day}` or `{input}` in it are replaced by the day and the quoted path of a file
+// holding the input data, which is also given on stdin for commands reading it there.
+pub fn run_reference(reference: &str, day: &u32, data: &str) -> Result<String, String> {
+    if reference.trim().is_empty() {
+        return Err("Reference command is empty.".into());
+    }
+
+    let input_path = std::env::temp_dir().join(format!(
+        "advent-of-code-2020-reference-day{:02}-{}.txt",
+        day,
+        std::process::id()
+    ));
+    if std::fs::write(&input_path, data).is_err() {
+        return Err("Unable to write input file for reference.".into());
+    }
+
+    let command = reference
+        .replace("{day}", &day.to_string())
+        .replace("{input}", &shell_quote(&input_path.to_string_lossy()));
+
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg(&command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .and_then(|mut child| {
+            // written from another thread so a command printing before reading cannot block,
+            // and errors are ignored since the command may never read its stdin
+            let mut stdin = child.stdin.take().expect("stdin is piped");
+            let stdin_data = data.to_string();
+            std::thread::spawn(move || stdin.write_all(stdin_data.as_bytes()));
+            child.wait_with_output()
+        });
+    let _ = std::fs::remove_file(&input_path);
+
+    match output {
+        Err(_) => Err(format!("Unable to run reference command: {}", reference)),
+        Ok(output) if !output.status.success() => Err(format!(
+            "Reference command failed for day {} with {}.",
+            day, output.status
+        )),
+        Ok(output) => Ok(String::from_utf8_lossy(&output.stdout).into_owned()),
+    }
+}
+
+// single quoted for `sh`, where a quote inside ends the quoting to be escaped itself
+fn shell_quote(argument: &str) -> String {
+    format!("'{}'", argument.replace('\'', "'\\''"))
+}
+
+// Accepts `silver: <answer>` and `gold: <answer>` lines (or `part 1` and `part 2`),
+// as well as the output of this runner, where each `Result:` follows its part and
+// results that are an `Err(...)` are not answers.
+pub fn parse_reference_output(output: &str) -> ChallengeAnswers {
+    let mut answers = ChallengeAnswers::default();
+    let mut current_part = None;
+
+    for line in output.lines().map(str::trim) {
+        let lowercase_line = line.to_lowercase();
+        if lowercase_line.starts_with("-> silver") {
+            current_part = Some(Part::Silver);
+            continue;
+        }
+        if lowercase_line.starts_with("-> gold") {
+            current_part = Some(Part::Gold);
+            continue;
+        }
+
+        // only the label is lowercased, as lowercasing may change the length of the line
+        let (label, answer) = match line.split_once(':') {
+            Some((label, answer)) => (label.trim().to_lowercase(), answer.trim()),
+            None => continue,
+        };
+        let part = match label.as_str() {
+            "silver" | "part 1" | "part1" => Some(Part::Silver),
+            "gold" | "part 2" | "part2" => Some(Part::Gold),
+            "result" if answer.starts_with("Err(") => None,
+            "result" => current_part,
+            _ => None,
+        };
+
+        match part {
+            Some(Part::Silver) => answers.silver = Some(answer.to_string()),
+            Some(Part::Gold) => answers.gold = Some(answer.to_string()),
+            None => {}
+        }
+    }
+
+    answers
+}
+
+// answers compare equal regardless of an `Ok(...)` wrapper or surrounding quotes
+pub fn normalize_answer(answer: &str) -> String {
+    let answer = answer.trim();
+    let answer = match answer.strip_prefix("Ok(") {
+        Some(inner) => inner.strip_suffix(')').unwrap_or(inner).trim(),
+        None => answer,
+    };
+    let answer = match answer.strip_prefix('"') {
+        Some(inner) => inner.strip_suffix('"').unwrap_or(inner),
+        None => answer,
+    };
+    answer.to_string()
+}
+
+// reports whether an answer matches the expected one, where the source of the
+// expected answers (such as a reference solver) may not have answered at all
+pub fn compare_part(
+    label: &str,
+    source: &str,
+    answer: &Option<String>,
+    expected: &Option<String>,
+) -> bool {
+    match (answer, expected) {
+        (_, None) => {
+            println!("    {:<8} no answer from {}", label, source);
+            true
+        }
+        (None, Some(expected)) => {
+            println!(
+                "    {:<8} differs, no answer here but {} has {}",
+                label,
+                source,
+                normalize_answer(expected)
+            );
+            false
+        }
+        (Some(answer), Some(expected))
+            if normalize_answer(answer) == normalize_answer(expected) =>
+        {
+            println!("    {:<8} matches {}", label, normalize_answer(answer));
+            true
+        }
+        (Some(answer), Some(expected)) => {
+            println!(
+                "    {:<8} differs, {} here but {} has {}",
+                label,
+                normalize_answer(answer),
+                source,
+                normalize_answer(expected)
+            );
+            false
+        }
+    }
+}
+
+pub fn compare_with_reference(
+    reference: &str,
+    day: &u32,
+    data: &str,
+    answers: &ChallengeAnswers,
+    compare_silver: bool,
+    compare_gold: bool,
+) -> Result<(), String> {
+    println!(" -> Reference");
+
+    let reference_answers = parse_reference_output(&run_reference(reference, day, data)?);
+    let silver_matches = !compare_silver
+        || compare_part(
+            "Silver:",
+            "reference",
+            &answers.silver,
+            &reference_answers.silver,
+        );
+    let gold_matches =
+        !compare_gold || compare_part("Gold:", "reference", &answers.gold, &reference_answers.gold);
+
+    match silver_matches && gold_matches {
+        true => Ok(()),
+        false => Err(format!(
+            "Answers for day {} differ from the reference.",
+            day
+        )),
+    }
+}
@@ -0,0 +1,77 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+#[test]
+fn labelled_answers_are_parsed() {
+    let answers = parse_reference_output("Day 1\nsilver: 514579\nGold: 241861950\n");
+
+    assert_eq!(answers.silver, Some("514579".into()));
+    assert_eq!(answers.gold, Some("241861950".into()));
+}
+
+#[test]
+fn part_numbers_are_parsed() {
+    let answers = parse_reference_output("Part 1: 7\npart2: 336");
+
+    assert_eq!(answers.silver, Some("7".into()));
+    assert_eq!(answers.gold, Some("336".into()));
+}
+
+#[test]
+fn runner_output_is_parsed() {
+    let output = "==> Day 1
+ -> Input data
+    Processing time:         16 μs
+ -> Silver
+    Processing time:          2 μs
+    Result: Ok(514579)
+ -> Gold
+    Processing time:         53 μs
+    Result: Err(\"NYI\")
+";
+    let answers = parse_reference_output(output);
+
+    assert_eq!(answers.silver, Some("Ok(514579)".into()));
+    assert_eq!(answers.gold, None);
+}
+
+#[test]
+fn non_ascii_labels_are_skipped() {
+    let answers = parse_reference_output("İ: 5\nsilver: 7\nGöld: 9");
+
+    assert_eq!(answers.silver, Some("7".into()));
+    assert_eq!(answers.gold, None);
+}
+
+#[test]
+fn missing_answers_are_none() {
+    assert_eq!(
+        parse_reference_output("nothing to see"),
+        ChallengeAnswers::default()
+    );
+}
+
+#[test]
+fn answers_are_normalized() {
+    assert_eq!(normalize_answer(" Ok(514579) "), "514579");
+    assert_eq!(normalize_answer("\"abc\""), "abc");
+    assert_eq!(normalize_answer("Ok(\"abc\")"), "abc");
+    assert_eq!(normalize_answer("Err(\"NYI\")"), "Err(\"NYI\")");
+}
+
+#[test]
+fn paths_are_quoted_for_the_shell() {
+    assert_eq!(shell_quote("/tmp/my input.txt"), "'/tmp/my input.txt'");
+    assert_eq!(shell_quote("it's"), "'it'\\''s'");
+}
+
+#[test]
+fn reference_runs_through_the_shell() {
+    let output = run_reference(
+        "printf '%s %s\\n' 'silver:' {day} && cat {input}",
+        &3,
+        "gold: 9",
+    );
+
+    assert_eq!(output, Ok("silver: 3\ngold: 9".into()));
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests;
+
+use crate::challenges::check_day_available;
+#[cfg(feature = "day05")]
+use advent_of_code_2020_challenges::Day05;
+#[cfg(feature = "day03")]
+use advent_of_code_2020_challenges::{Day03, Slope};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RenderOutput {
+    Print,
+    Text(PathBuf),
+    Image(PathBuf),
+}
+
+// only read by renderers of days which may not be compiled in
+#[cfg_attr(not(feature = "day03"), allow(dead_code))]
+pub struct RenderOptions {
+    right: usize,
+    down: usize,
+    output: RenderOutput,
+    scale: usize,
+}
+
+impl RenderOptions {
+    pub fn new(right: usize, down: usize, output: RenderOutput, scale: usize) -> Self {
+        RenderOptions {
+            right,
+            down,
+            output,
+            scale,
+        }
+    }
+}
+
+// draws the map of a day from its input, for the days with something to draw
+type Renderer = fn(&str, &RenderOptions) -> Result<(), String>;
+
+// files ending in `.ppm` are written as images, any other file as text
+pub fn render_output(file_path: &Option<PathBuf>) -> RenderOutput {
+    match file_path {
+        None => RenderOutput::Print,
+        Some(file_path) => match file_path
+            .extension()
+            .and_then(|extension| extension.to_str())
+        {
+            Some(extension) if extension.eq_ignore_ascii_case("ppm") => {
+                RenderOutput::Image(file_path.clone())
+            }
+            _ => RenderOutput::Text(file_path.clone()),
+        },
+    }
+}
+
+pub fn parse_scale(scale: &str) -> Result<usize, String> {
+    match scale.trim().parse() {
+        Ok(0) => Err("Scale must be at least 1.".into()),
+        Ok(scale) => Ok(scale),
+        Err(_) => Err(format!("Unrecognized scale given: {}.", scale)),
+    }
+}
+
+#[cfg(any(feature = "day03", feature = "day05"))]
+fn write_file(file_path: &std::path::Path, contents: &[u8]) -> Result<(), String> {
+    std::fs::write(file_path, contents)
+        .map_err(|error| format!("Unable to write {}: {}", file_path.display(), error))
+}
+
+#[cfg(feature = "day03")]
+fn render_path(data: &str, options: &RenderOptions) -> Result<(), String> {
+    let slope = Slope::new(options.right, options.down)?;
+    let challenge = Day03::new(data)?;
+    let render = challenge.map().render_path(slope);
+    println!(
+        " -> Slope {} hits {} trees",
+        slope,
+        challenge.map().count_trees(slope)
+    );
+
+    match &options.output {
+        RenderOutput::Print => {
+            for line in render.to_string().lines() {
+                println!("    {}", line);
+            }
+        }
+        RenderOutput::Text(file_path) => {
+            write_file(file_path, format!("{}\n", render).as_bytes())?;
+            println!("    Written to {}", file_path.display());
+        }
+        RenderOutput::Image(file_path) => {
+            write_file(file_path, &render.to_ppm(options.scale))?;
+            println!("    Written to {}", file_path.display());
+        }
+    }
+
+    Ok(())
+}
+
+// the grid of taken seats, as there is no image of a seat map
+#[cfg(feature = "day05")]
+fn render_seat_map(data: &str, options: &RenderOptions) -> Result<(), String> {
+    let challenge = Day05::new(data)?;
+    let seat_map = challenge.seat_map();
+    println!(
+        " -> {} of {} seats taken",
+        seat_map.taken_seats(),
+        seat_map.geometry().seats()
+    );
+
+    match &options.output {
+        RenderOutput::Print => {
+            for line in seat_map.to_string().lines() {
+                println!("    {}", line);
+            }
+        }
+        RenderOutput::Text(file_path) => {
+            write_file(file_path, format!("{}\n", seat_map).as_bytes())?;
+            println!("    Written to {}", file_path.display());
+        }
+        RenderOutput::Image(file_path) => {
+            return Err(format!(
+                "Seat maps can only be written as text, not to {}.",
+                file_path.display()
+            ))
+        }
+    }
+
+    Ok(())
+}
+
+pub fn render_challenge_for_day(
+    day: &u32,
+    data: String,
+    options: &RenderOptions,
+) -> Result<(), String> {
+    check_day_available(day)?;
+    let render: Option<Renderer> = match day {
+        #[cfg(feature = "day03")]
+        3 => Some(render_path),
+        #[cfg(feature = "day05")]
+        5 => Some(render_seat_map),
+        _ => None,
+    };
+    let render = render.ok_or_else(|| format!("Day {} has nothing to render.", day))?;
+
+    println!("==> Day {}", day);
+    render(&data, options)?;
+    println!();
+    Ok(())
+}
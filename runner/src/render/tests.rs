@@ -0,0 +1,73 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+#[test]
+fn output_is_printed_without_a_file() {
+    assert_eq!(render_output(&None), RenderOutput::Print);
+}
+
+#[test]
+fn output_format_follows_file_extension() {
+    assert_eq!(
+        render_output(&Some("path.ppm".into())),
+        RenderOutput::Image("path.ppm".into())
+    );
+    assert_eq!(
+        render_output(&Some("path.PPM".into())),
+        RenderOutput::Image("path.PPM".into())
+    );
+    assert_eq!(
+        render_output(&Some("path.txt".into())),
+        RenderOutput::Text("path.txt".into())
+    );
+    assert_eq!(
+        render_output(&Some("path".into())),
+        RenderOutput::Text("path".into())
+    );
+}
+
+#[test]
+fn scale_must_be_positive() {
+    assert_eq!(parse_scale("4"), Ok(4));
+    assert_eq!(parse_scale("0"), Err("Scale must be at least 1.".into()));
+    assert_eq!(
+        parse_scale("big"),
+        Err("Unrecognized scale given: big.".into())
+    );
+}
+
+#[cfg(feature = "day03")]
+#[test]
+fn rendering_is_written_to_files() {
+    let directory =
+        std::env::temp_dir().join(format!("advent-of-code-2020-render-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let (text_path, image_path) = (directory.join("path.txt"), directory.join("path.ppm"));
+
+    for file_path in [&text_path, &image_path].iter() {
+        let options = RenderOptions::new(2, 1, render_output(&Some(file_path.to_path_buf())), 3);
+        render_challenge_for_day(&3, "..#\n#..\n.#.".into(), &options).unwrap();
+    }
+
+    assert_eq!(
+        std::fs::read_to_string(&text_path).unwrap(),
+        "..#..#\n#.O#..\n.#..X.\n"
+    );
+    let image = std::fs::read(&image_path).unwrap();
+    assert!(image.starts_with(b"P6\n18 9\n255\n"));
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[cfg(feature = "day25")]
+#[test]
+fn days_without_rendering_fail() {
+    assert_eq!(
+        render_challenge_for_day(
+            &25,
+            "".into(),
+            &RenderOptions::new(3, 1, RenderOutput::Print, 1)
+        ),
+        Err("Day 25 has nothing to render.".into())
+    );
+}
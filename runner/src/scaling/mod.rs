@@ -0,0 +1,170 @@
+// scaling is only possible for days with an input generator, none of which may be compiled in
+#![cfg_attr(
+    not(any(feature = "day01", feature = "day02", feature = "day03")),
+    allow(dead_code, unused_variables)
+)]
+
+#[cfg(test)]
+mod tests;
+
+use crate::challenges::{check_day_available, is_day_available};
+use advent_of_code_2020_challenges::*;
+use separator::Separatable;
+use std::time::{Duration, Instant};
+
+pub struct ScaleOptions {
+    sizes: Vec<usize>,
+    seed: u64,
+    solve_silver: bool,
+    solve_gold: bool,
+}
+
+impl ScaleOptions {
+    pub fn new(sizes: Vec<usize>, seed: u64, solve_silver: bool, solve_gold: bool) -> Self {
+        ScaleOptions {
+            sizes,
+            seed,
+            solve_silver,
+            solve_gold,
+        }
+    }
+}
+
+// accepts plain counts as well as `k` and `m` suffixes, e.g. `500`, `10k` or `1m`
+pub fn parse_scale_size(size: &str) -> Result<usize, String> {
+    let lowercase_size = size.trim().to_lowercase();
+    let (digits, multiplier) = match lowercase_size.chars().last() {
+        Some('k') => (&lowercase_size[..lowercase_size.len() - 1], 1_000),
+        Some('m') => (&lowercase_size[..lowercase_size.len() - 1], 1_000_000),
+        _ => (lowercase_size.as_str(), 1),
+    };
+
+    match digits.parse::<usize>() {
+        Ok(count) if count > 0 => count
+            .checked_mul(multiplier)
+            .ok_or_else(|| format!("Scale size {} is too large.", size)),
+        _ => Err(format!("Invalid scale size given: {}.", size)),
+    }
+}
+
+// least squares slope of log(time) over log(size), which is the exponent `k` in O(n^k)
+fn estimate_growth_exponent(samples: &[(usize, Duration)]) -> Option<f64> {
+    let points = samples
+        .iter()
+        .map(|(size, time)| ((*size as f64).ln(), (time.as_nanos().max(1) as f64).ln()))
+        .collect::<Vec<_>>();
+
+    let count = points.len() as f64;
+    let mean_size = points.iter().map(|(size, _)| size).sum::<f64>() / count;
+    let mean_time = points.iter().map(|(_, time)| time).sum::<f64>() / count;
+
+    let covariance = points
+        .iter()
+        .map(|(size, time)| (size - mean_size) * (time - mean_time))
+        .sum::<f64>();
+    let variance = points
+        .iter()
+        .map(|(size, _)| (size - mean_size).powi(2))
+        .sum::<f64>();
+
+    match variance > 0.0 {
+        true => Some(covariance / variance),
+        false => None,
+    }
+}
+
+fn print_growth_estimate(label: &str, samples: &[(usize, Duration)]) {
+    match estimate_growth_exponent(samples) {
+        Some(exponent) => println!("    {:<12} O(n^{:.2})", label, exponent),
+        None => println!("    {:<12} needs at least two distinct sizes", label),
+    }
+}
+
+pub fn scale_challenges<D, F>(parse_challenge: F, options: &ScaleOptions) -> Result<(), String>
+where
+    D: SilverChallenge + GoldChallenge + InputGenerator,
+    <D as SilverChallenge>::Answer: std::fmt::Debug,
+    <D as SilverChallenge>::Error: std::fmt::Debug,
+    <D as GoldChallenge>::Answer: std::fmt::Debug,
+    <D as GoldChallenge>::Error: std::fmt::Debug,
+    F: Fn(&str) -> Result<D, String>,
+{
+    let mut parse_samples = Vec::new();
+    let mut silver_samples = Vec::new();
+    let mut gold_samples = Vec::new();
+
+    for size in &options.sizes {
+        println!(" -> Size {:>12}", size.separated_string());
+
+        // input generation is deliberately kept out of the timed sections
+        let data = D::generate_input(*size, options.seed);
+
+        let data_parse_start_time = Instant::now();
+        let mut challenge = parse_challenge(data.as_str())?;
+        let data_parse_total_time = data_parse_start_time.elapsed();
+        parse_samples.push((*size, data_parse_total_time));
+        println!(
+            "    Input data:  {:>10} μs",
+            data_parse_total_time.as_micros().separated_string()
+        );
+
+        if options.solve_silver {
+            let silver_start_time = Instant::now();
+            let _ = challenge.attempt_silver();
+            let silver_total_time = silver_start_time.elapsed();
+            silver_samples.push((*size, silver_total_time));
+            println!(
+                "    Silver:      {:>10} μs",
+                silver_total_time.as_micros().separated_string()
+            );
+        }
+
+        if options.solve_gold {
+            let gold_start_time = Instant::now();
+            let _ = challenge.attempt_gold();
+            let gold_total_time = gold_start_time.elapsed();
+            gold_samples.push((*size, gold_total_time));
+            println!(
+                "    Gold:        {:>10} μs",
+                gold_total_time.as_micros().separated_string()
+            );
+        }
+    }
+
+    println!(" -> Estimated growth");
+    print_growth_estimate("Input data:", &parse_samples);
+    if options.solve_silver {
+        print_growth_estimate("Silver:", &silver_samples);
+    }
+    if options.solve_gold {
+        print_growth_estimate("Gold:", &gold_samples);
+    }
+
+    Ok(())
+}
+
+pub fn has_input_generator(day: &u32) -> bool {
+    matches!(day, 1..=3) && is_day_available(*day)
+}
+
+pub fn scale_challenges_for_day(day: &u32, options: &ScaleOptions) -> Result<(), String> {
+    check_day_available(day)?;
+    if !has_input_generator(day) {
+        return Err(format!("No input generator available for day {}.", day));
+    }
+
+    println!("==> Day {}", day);
+
+    match day {
+        #[cfg(feature = "day01")]
+        1 => scale_challenges(Day01::new, options),
+        #[cfg(feature = "day02")]
+        2 => scale_challenges(Day02::new, options),
+        #[cfg(feature = "day03")]
+        3 => scale_challenges(Day03::new, options),
+        _ => Err(format!("No input generator available for day {}.", day)),
+    }?;
+
+    println!();
+    Ok(())
+}
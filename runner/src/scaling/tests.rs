@@ -0,0 +1,20 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+#[test]
+fn sizes_take_thousands_and_millions() {
+    assert_eq!(parse_scale_size("250"), Ok(250));
+    assert_eq!(parse_scale_size("10k"), Ok(10_000));
+    assert_eq!(parse_scale_size("2M"), Ok(2_000_000));
+    assert!(parse_scale_size("0").is_err());
+    assert!(parse_scale_size("k").is_err());
+}
+
+#[test]
+fn oversized_sizes_are_rejected() {
+    let size = format!("{}m", usize::MAX);
+    assert_eq!(
+        parse_scale_size(&size),
+        Err(format!("Scale size {} is too large.", size))
+    );
+}
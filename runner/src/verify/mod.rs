@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests;
+
+use crate::challenges::ChallengeAnswers;
+use crate::reference::{compare_part, normalize_answer, parse_reference_output};
+use std::path::{Path, PathBuf};
+
+// answers are recorded in the same `silver: <answer>` format a reference may print,
+// in the answers directory resolved like the input directory
+pub fn recorded_answers_file_path(answers_dir: &Path, day: &u32) -> PathBuf {
+    answers_dir.join(format!("day{:02}.txt", day))
+}
+
+pub fn format_recorded_answers(answers: &ChallengeAnswers) -> String {
+    let mut recorded = String::new();
+    if let Some(silver) = &answers.silver {
+        recorded.push_str(&format!("silver: {}\n", normalize_answer(silver)));
+    }
+    if let Some(gold) = &answers.gold {
+        recorded.push_str(&format!("gold: {}\n", normalize_answer(gold)));
+    }
+    recorded
+}
+
+pub fn read_recorded_answers(
+    answers_dir: &Path,
+    day: &u32,
+) -> Result<Option<ChallengeAnswers>, String> {
+    let file_path = recorded_answers_file_path(answers_dir, day);
+    if !file_path.is_file() {
+        return Ok(None);
+    }
+
+    std::fs::read_to_string(&file_path)
+        .map(|recorded| Some(parse_reference_output(&recorded)))
+        .map_err(|error| format!("Unable to read {}: {}", file_path.display(), error))
+}
+
+// only the parts that were solved are recorded, keeping any answer already
+// recorded for a part that was not solved this time
+pub fn record_answers(
+    answers_dir: &Path,
+    day: &u32,
+    answers: &ChallengeAnswers,
+) -> Result<PathBuf, String> {
+    let previous_answers = read_recorded_answers(answers_dir, day)?.unwrap_or_default();
+    let answers = ChallengeAnswers {
+        silver: answers.silver.clone().or(previous_answers.silver),
+        gold: answers.gold.clone().or(previous_answers.gold),
+    };
+
+    let file_path = recorded_answers_file_path(answers_dir, day);
+    if let Some(directory) = file_path.parent() {
+        std::fs::create_dir_all(directory)
+            .map_err(|error| format!("Unable to create {}: {}", directory.display(), error))?;
+    }
+    std::fs::write(&file_path, format_recorded_answers(&answers))
+        .map_err(|error| format!("Unable to write {}: {}", file_path.display(), error))?;
+
+    Ok(file_path)
+}
+
+pub fn verify_recorded_answers(
+    answers_dir: &Path,
+    day: &u32,
+    answers: &ChallengeAnswers,
+    verify_silver: bool,
+    verify_gold: bool,
+) -> Result<(), String> {
+    println!(" -> Recorded answers");
+
+    let recorded_answers = match read_recorded_answers(answers_dir, day)? {
+        Some(recorded_answers) => recorded_answers,
+        None => {
+            println!("    No answers recorded, record them with `verify --record`");
+            return Ok(());
+        }
+    };
+
+    let silver_matches = !verify_silver
+        || compare_part(
+            "Silver:",
+            "record",
+            &answers.silver,
+            &recorded_answers.silver,
+        );
+    let gold_matches =
+        !verify_gold || compare_part("Gold:", "record", &answers.gold, &recorded_answers.gold);
+
+    match silver_matches && gold_matches {
+        true => Ok(()),
+        false => Err(format!(
+            "Answers for day {} differ from those recorded.",
+            day
+        )),
+    }
+}
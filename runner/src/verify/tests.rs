@@ -0,0 +1,45 @@
+// import everything from the parent module file (in this case mod.rs)
+use super::*;
+
+#[test]
+fn answers_file_is_named_by_day() {
+    assert_eq!(
+        recorded_answers_file_path(Path::new("answers"), &7),
+        PathBuf::from("answers").join("day07.txt")
+    );
+}
+
+#[test]
+fn recorded_answers_are_normalized() {
+    let answers = ChallengeAnswers {
+        silver: Some("514579".into()),
+        gold: Some("\"Merry Christmas\"".into()),
+    };
+
+    assert_eq!(
+        format_recorded_answers(&answers),
+        "silver: 514579\ngold: Merry Christmas\n"
+    );
+}
+
+#[test]
+fn unsolved_parts_are_not_recorded() {
+    let answers = ChallengeAnswers {
+        silver: None,
+        gold: Some("336".into()),
+    };
+
+    assert_eq!(format_recorded_answers(&answers), "gold: 336\n");
+}
+
+#[test]
+fn recorded_answers_parse_back() {
+    let answers = ChallengeAnswers {
+        silver: Some("7".into()),
+        gold: Some("\"abc\"".into()),
+    };
+    let recorded = parse_reference_output(&format_recorded_answers(&answers));
+
+    assert_eq!(recorded.silver, Some("7".into()));
+    assert_eq!(recorded.gold, Some("abc".into()));
+}
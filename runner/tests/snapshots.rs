@@ -0,0 +1,388 @@
+// Golden file tests of the runner's printed output, comparing stdout, stderr and
+// the exit status of the binary against the files in `tests/snapshots`. Timings are
+// masked since they change between runs. To accept changed output, run with
+// `UPDATE_SNAPSHOTS=1 cargo test -p advent-of-code-2020-runner --test snapshots`.
+//
+// The runner is run from `tests/fixtures`, whose `input` and `answers` directories
+// only hold an input and recorded answers for day 1, pinned to the sample of its
+// puzzle so the output only changes when the runner does.
+use std::path::PathBuf;
+use std::process::Command;
+
+fn manifest_path(parts: &[&str]) -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.extend(parts);
+    path
+}
+
+fn mask_timings(output: &str) -> String {
+    output
+        .lines()
+        .map(|line| {
+            let label = ["Processing time:", "Mean time:", "Best time:"]
+                .iter()
+                .find_map(|label| line.find(label).map(|index| (index, label)));
+            match label {
+                Some((index, label)) => format!("{}{} <masked> μs", &line[..index], label),
+                None => line.to_string(),
+            }
+        })
+        .map(|line| line + "\n")
+        .collect()
+}
+
+// the user's own config and environment are left out, so only the settings given apply
+fn run_runner(args: &[&str], envs: &[(&str, &str)]) -> String {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_advent-of-code-2020-runner"));
+    command
+        .args(args)
+        .current_dir(manifest_path(&["tests", "fixtures"]))
+        .env_remove("CHALLENGE_DAY")
+        .env_remove("INPUT_FILE")
+        .env_remove("INPUT_DATA")
+        .env_remove("XDG_CONFIG_HOME")
+        .env("HOME", manifest_path(&["tests", "fixtures"]));
+    for (name, _) in std::env::vars().filter(|(name, _)| name.starts_with("AOC_")) {
+        command.env_remove(name);
+    }
+    let output = command
+        .envs(envs.iter().copied())
+        .output()
+        .expect("runner binary should start");
+
+    format!(
+        "--- args\n{}\n--- status\n{}\n--- stdout\n{}--- stderr\n{}",
+        args.join(" "),
+        output.status.code().unwrap_or(-1),
+        mask_timings(&String::from_utf8_lossy(&output.stdout)),
+        mask_timings(&String::from_utf8_lossy(&output.stderr)),
+    )
+}
+
+fn assert_snapshot(name: &str, args: &[&str]) {
+    assert_snapshot_with_env(name, args, &[]);
+}
+
+fn assert_snapshot_with_env(name: &str, args: &[&str], envs: &[(&str, &str)]) {
+    let actual = run_runner(args, envs);
+    let snapshot_path = manifest_path(&["tests", "snapshots", &format!("{}.snap", name)]);
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&snapshot_path, &actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&snapshot_path)
+        .unwrap_or_else(|_| panic!("missing snapshot {}, run with UPDATE_SNAPSHOTS=1", name));
+    assert_eq!(
+        actual, expected,
+        "runner output differs from snapshot {}",
+        name
+    );
+}
+
+#[test]
+fn all_days_with_input() {
+    assert_snapshot("all_days_with_input", &[]);
+}
+
+#[test]
+fn single_day() {
+    assert_snapshot("single_day", &["--day", "1"]);
+}
+
+#[test]
+fn inspect_single_day() {
+    assert_snapshot("inspect_single_day", &["inspect", "--day", "1"]);
+}
+
+#[test]
+fn inspect_data_override() {
+    assert_snapshot(
+        "inspect_data_override",
+        &[
+            "inspect",
+            "--day",
+            "4",
+            "-d",
+            "ecl:gry pid:860033327\n\nhgt:59in",
+        ],
+    );
+}
+
+#[test]
+fn run_single_day() {
+    assert_snapshot("run_single_day", &["run", "--day", "1"]);
+}
+
+#[test]
+fn bench_single_day() {
+    assert_snapshot(
+        "bench_single_day",
+        &["bench", "--day", "1", "--iterations", "3"],
+    );
+}
+
+#[test]
+fn bench_without_iterations_fails() {
+    assert_snapshot(
+        "bench_without_iterations_fails",
+        &["bench", "--day", "1", "--iterations", "0"],
+    );
+}
+
+#[test]
+fn verify_single_day() {
+    assert_snapshot("verify_single_day", &["verify", "--day", "1"]);
+}
+
+// answers are looked up in the configured directory rather than the current one
+#[test]
+fn verify_with_answers_dir_from_env() {
+    assert_snapshot_with_env(
+        "verify_with_answers_dir_from_env",
+        &["verify", "--day", "1"],
+        &[("AOC_ANSWERS_DIR", "elsewhere")],
+    );
+}
+
+#[test]
+fn list_days() {
+    assert_snapshot("list_days", &["list"]);
+}
+
+#[test]
+fn config_show_defaults() {
+    assert_snapshot("config_show_defaults", &["config", "show"]);
+}
+
+#[test]
+fn config_show_with_env_and_command_line() {
+    assert_snapshot_with_env(
+        "config_show_with_env_and_command_line",
+        &["--input-dir", "elsewhere", "config", "show"],
+        &[("AOC_SEED", "42"), ("AOC_INPUT_DIR", "puzzles")],
+    );
+}
+
+#[test]
+fn single_day_hide_timing_from_env() {
+    assert_snapshot_with_env(
+        "single_day_hide_timing_from_env",
+        &["--day", "1"],
+        &[("AOC_HIDE_TIMING", "true")],
+    );
+}
+
+#[test]
+fn single_day_show_timing_over_env() {
+    assert_snapshot_with_env(
+        "single_day_show_timing_over_env",
+        &["--day", "1", "--no-hide-timing"],
+        &[("AOC_HIDE_TIMING", "true")],
+    );
+}
+
+#[test]
+fn invalid_env_setting_fails() {
+    assert_snapshot_with_env(
+        "invalid_env_setting_fails",
+        &["--day", "1"],
+        &[("AOC_ITERATIONS", "many")],
+    );
+}
+
+#[test]
+fn single_day_with_target() {
+    assert_snapshot(
+        "single_day_with_target",
+        &[
+            "--day",
+            "1",
+            "-d",
+            "1721\n979\n366\n299\n675\n1456",
+            "--target",
+            "1345",
+            "--hide-timing",
+        ],
+    );
+}
+
+#[test]
+fn single_day_verbose_report() {
+    assert_snapshot(
+        "single_day_verbose_report",
+        &[
+            "--day",
+            "2",
+            "-d",
+            "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc",
+            "--verbose",
+            "--hide-timing",
+        ],
+    );
+}
+
+#[test]
+fn render_path() {
+    assert_snapshot(
+        "render_path",
+        &[
+            "render",
+            "--day",
+            "3",
+            "-d",
+            "..##.......\n#...#...#..\n.#....#..#.\n..#.#...#.#\n.#...##..#.",
+            "--right",
+            "5",
+        ],
+    );
+}
+
+#[test]
+fn render_seat_map() {
+    assert_snapshot(
+        "render_seat_map",
+        &[
+            "render",
+            "--day",
+            "5",
+            "-d",
+            "FFFFFFFLLR\nFFFFFFFLRR\nFFFFFFBLLL\nFFFFFFBRRL",
+        ],
+    );
+}
+
+#[test]
+fn single_day_verbose_seat_gaps() {
+    assert_snapshot(
+        "single_day_verbose_seat_gaps",
+        &[
+            "--day",
+            "5",
+            "-d",
+            "FFFFFFFLLR\nFFFFFFFLRR\nFFFFFFBLLL",
+            "--verbose",
+            "--hide-timing",
+        ],
+    );
+}
+
+#[test]
+fn render_for_other_day_fails() {
+    assert_snapshot("render_for_other_day_fails", &["render", "--day", "1"]);
+}
+
+#[test]
+fn target_for_other_day_fails() {
+    assert_snapshot(
+        "target_for_other_day_fails",
+        &["--day", "2", "-d", "1-3 a: abcde", "--target", "1345"],
+    );
+}
+
+#[test]
+fn single_day_hide_timing() {
+    assert_snapshot("single_day_hide_timing", &["--day", "1", "--hide-timing"]);
+}
+
+#[test]
+fn single_day_hide_solutions() {
+    assert_snapshot(
+        "single_day_hide_solutions",
+        &["--day", "1", "--hide-solutions"],
+    );
+}
+
+#[test]
+fn single_day_silver_only() {
+    assert_snapshot("single_day_silver_only", &["--day", "1", "-s"]);
+}
+
+#[test]
+fn single_day_gold_only() {
+    assert_snapshot("single_day_gold_only", &["--day", "1", "-g"]);
+}
+
+#[test]
+fn single_day_silver_and_gold() {
+    assert_snapshot("single_day_silver_and_gold", &["--day", "1", "-s", "-g"]);
+}
+
+#[test]
+fn data_override() {
+    assert_snapshot(
+        "data_override",
+        &["--day", "1", "-d", "1010\n1000\n1020\n10"],
+    );
+}
+
+#[test]
+fn file_override() {
+    assert_snapshot("file_override", &["--day", "1", "-f", "input/day01.txt"]);
+}
+
+#[test]
+fn data_without_day_fails() {
+    assert_snapshot("data_without_day_fails", &["-d", "1721"]);
+}
+
+#[test]
+fn file_without_day_fails() {
+    assert_snapshot("file_without_day_fails", &["-f", "input/day01.txt"]);
+}
+
+#[test]
+fn data_and_file_fails() {
+    assert_snapshot(
+        "data_and_file_fails",
+        &["--day", "1", "-d", "1721", "-f", "input/day01.txt"],
+    );
+}
+
+#[test]
+fn unreadable_file_fails() {
+    assert_snapshot(
+        "unreadable_file_fails",
+        &["--day", "1", "-f", "missing.txt"],
+    );
+}
+
+#[test]
+fn missing_input_for_day_fails() {
+    assert_snapshot("missing_input_for_day_fails", &["--day", "24"]);
+}
+
+#[test]
+fn blank_input_fails() {
+    assert_snapshot("blank_input_fails", &["--day", "1", "-f", "blank.txt"]);
+}
+
+#[test]
+fn unrecognized_day_fails() {
+    assert_snapshot("unrecognized_day_fails", &["--day", "26", "-d", "1721"]);
+}
+
+#[cfg(unix)]
+#[test]
+fn single_day_with_reference() {
+    assert_snapshot(
+        "single_day_with_reference",
+        &["--day", "1", "--reference", "sh reference.sh {day} {input}"],
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn single_day_with_failing_reference() {
+    assert_snapshot(
+        "single_day_with_failing_reference",
+        &[
+            "--day",
+            "1",
+            "-s",
+            "--reference",
+            "sh reference.sh 24 {input}",
+        ],
+    );
+}
@@ -0,0 +1,178 @@
+use advent_of_code_2020_challenges::*;
+use wasm_bindgen::prelude::*;
+
+// `std::time::Instant` is not available on wasm32-unknown-unknown,
+// so timings come from the host's `performance.now()` instead
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = performance, js_name = now)]
+    fn performance_now() -> f64;
+}
+
+#[cfg(target_arch = "wasm32")]
+fn now_in_milliseconds() -> f64 {
+    performance_now()
+}
+
+// measured from the first call, as `Instant` is monotonic but has no fixed epoch
+#[cfg(not(target_arch = "wasm32"))]
+fn now_in_milliseconds() -> f64 {
+    use std::sync::OnceLock;
+    use std::time::Instant;
+
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_secs_f64() * 1_000.0
+}
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    ok: bool,
+    answer: String,
+    parse_milliseconds: f64,
+    solve_milliseconds: f64,
+}
+
+#[wasm_bindgen]
+impl Solution {
+    /// Whether the challenge returned an answer rather than an error
+    #[wasm_bindgen(getter)]
+    pub fn ok(&self) -> bool {
+        self.ok
+    }
+
+    /// Debug representation of the answer, or of the error when not ok
+    #[wasm_bindgen(getter)]
+    pub fn answer(&self) -> String {
+        self.answer.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = parseMilliseconds)]
+    pub fn parse_milliseconds(&self) -> f64 {
+        self.parse_milliseconds
+    }
+
+    #[wasm_bindgen(getter, js_name = solveMilliseconds)]
+    pub fn solve_milliseconds(&self) -> f64 {
+        self.solve_milliseconds
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Part {
+    Silver,
+    Gold,
+}
+
+impl Part {
+    fn parse(part: &str) -> Result<Self, String> {
+        match part.trim().to_lowercase().as_str() {
+            "silver" | "1" => Ok(Part::Silver),
+            "gold" | "2" => Ok(Part::Gold),
+            _ => Err(format!("Unrecognized part given: {}.", part)),
+        }
+    }
+}
+
+fn solve_challenge<D, F>(parse_challenge: F, part: Part, data: &str) -> Result<Solution, String>
+where
+    D: SilverChallenge + GoldChallenge,
+    <D as SilverChallenge>::Answer: std::fmt::Debug,
+    <D as SilverChallenge>::Error: std::fmt::Debug,
+    <D as GoldChallenge>::Answer: std::fmt::Debug,
+    <D as GoldChallenge>::Error: std::fmt::Debug,
+    F: Fn(&str) -> Result<D, String>,
+{
+    let data_parse_start_time = now_in_milliseconds();
+    let mut challenge = parse_challenge(data)?;
+    let parse_milliseconds = now_in_milliseconds() - data_parse_start_time;
+
+    let solve_start_time = now_in_milliseconds();
+    let (ok, answer) = match part {
+        Part::Silver => match challenge.attempt_silver() {
+            Ok(answer) => (true, format!("{:?}", answer)),
+            Err(error) => (false, format!("{:?}", error)),
+        },
+        Part::Gold => match challenge.attempt_gold() {
+            Ok(answer) => (true, format!("{:?}", answer)),
+            Err(error) => (false, format!("{:?}", error)),
+        },
+    };
+    let solve_milliseconds = now_in_milliseconds() - solve_start_time;
+
+    Ok(Solution {
+        ok,
+        answer,
+        parse_milliseconds,
+        solve_milliseconds,
+    })
+}
+
+/// Solves one part (`"silver"` or `"gold"`) of a day for the given puzzle input.
+///
+/// Input that fails to parse, or an unknown day or part, is thrown as an error.
+#[wasm_bindgen]
+pub fn solve(day: u32, part: &str, input: &str) -> Result<Solution, JsValue> {
+    solve_day(day, part, input).map_err(|error| JsValue::from_str(&error))
+}
+
+fn solve_day(day: u32, part: &str, input: &str) -> Result<Solution, String> {
+    let part = Part::parse(part)?;
+    let data = normalize_day_input(day, input, false)?;
+    let data_str = data.as_str();
+
+    match day {
+        #[cfg(feature = "day01")]
+        1 => solve_challenge(Day01::new, part, data_str),
+        #[cfg(feature = "day02")]
+        2 => solve_challenge(Day02::new, part, data_str),
+        #[cfg(feature = "day03")]
+        3 => solve_challenge(Day03::new, part, data_str),
+        #[cfg(feature = "day04")]
+        4 => solve_challenge(Day04::new, part, data_str),
+        #[cfg(feature = "day05")]
+        5 => solve_challenge(Day05::new, part, data_str),
+        #[cfg(feature = "day06")]
+        6 => solve_challenge(Day06::new, part, data_str),
+        #[cfg(feature = "day07")]
+        7 => solve_challenge(Day07::new, part, data_str),
+        #[cfg(feature = "day08")]
+        8 => solve_challenge(Day08::new, part, data_str),
+        #[cfg(feature = "day09")]
+        9 => solve_challenge(Day09::new, part, data_str),
+        #[cfg(feature = "day10")]
+        10 => solve_challenge(Day10::new, part, data_str),
+        #[cfg(feature = "day11")]
+        11 => solve_challenge(Day11::new, part, data_str),
+        #[cfg(feature = "day12")]
+        12 => solve_challenge(Day12::new, part, data_str),
+        #[cfg(feature = "day13")]
+        13 => solve_challenge(Day13::new, part, data_str),
+        #[cfg(feature = "day14")]
+        14 => solve_challenge(Day14::new, part, data_str),
+        #[cfg(feature = "day15")]
+        15 => solve_challenge(Day15::new, part, data_str),
+        #[cfg(feature = "day16")]
+        16 => solve_challenge(Day16::new, part, data_str),
+        #[cfg(feature = "day17")]
+        17 => solve_challenge(Day17::new, part, data_str),
+        #[cfg(feature = "day18")]
+        18 => solve_challenge(Day18::new, part, data_str),
+        #[cfg(feature = "day19")]
+        19 => solve_challenge(Day19::new, part, data_str),
+        #[cfg(feature = "day20")]
+        20 => solve_challenge(Day20::new, part, data_str),
+        #[cfg(feature = "day21")]
+        21 => solve_challenge(Day21::new, part, data_str),
+        #[cfg(feature = "day22")]
+        22 => solve_challenge(Day22::new, part, data_str),
+        #[cfg(feature = "day23")]
+        23 => solve_challenge(Day23::new, part, data_str),
+        #[cfg(feature = "day24")]
+        24 => solve_challenge(Day24::new, part, data_str),
+        #[cfg(feature = "day25")]
+        25 => solve_challenge(Day25::new, part, data_str),
+        _ => Err(format!("Unrecognized date given: {}.", day)),
+    }
+}
@@ -0,0 +1,37 @@
+// run headless with `wasm-pack test --node wasm`, or natively with `cargo test`
+// each test needs the days it solves, which may not be compiled in
+#![cfg(any(feature = "day01", feature = "day02"))]
+
+use advent_of_code_2020_wasm::solve;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(feature = "day01")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn solve_returns_solution_with_timings() {
+    let solution = solve(1, "silver", "1721\r\n979\r\n366\r\n299\r\n675\r\n1456\r\n").unwrap();
+
+    assert!(solution.parse_milliseconds() >= 0.0);
+    assert!(solution.solve_milliseconds() >= 0.0);
+    assert!(solution.ok());
+    assert_eq!(solution.answer(), "514579");
+}
+
+#[cfg(feature = "day02")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn solve_accepts_part_names_and_numbers() {
+    for part in &["silver", "Gold", "1", "2"] {
+        assert!(solve(2, part, "1-3 a: abcde").is_ok());
+    }
+}
+
+// rejected input is thrown as a JavaScript error, which only exists on wasm targets
+#[cfg(all(target_arch = "wasm32", feature = "day01"))]
+#[wasm_bindgen_test]
+fn solve_rejects_unknown_day_part_and_empty_input() {
+    assert!(solve(26, "silver", "1721").is_err());
+    assert!(solve(1, "bronze", "1721").is_err());
+    assert!(solve(1, "silver", "\r\n").is_err());
+}